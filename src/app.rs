@@ -1,27 +1,88 @@
+use chrono::{DateTime, Utc};
 use color_eyre::eyre::anyhow;
 use color_eyre::Result;
-use crossterm::event::{self, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{
+    self, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::{Paragraph, Wrap};
 use ratatui::DefaultTerminal;
 use sms_client::http::HttpClient;
 use sms_client::types::events::Event;
-use sms_client::ws::events::WebsocketEvent;
+use sms_client::types::http::HttpPaginationOptions;
+use sms_client::ws::events::{WebsocketEvent, WebsocketReconnectionKind};
 use sms_client::Client;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tokio::time::interval;
 
+use crate::connection::{format_version, ServerVersionCheck, WebSocketStatus, MIN_SERVER_VERSION};
+use crate::contacts::ContactCache;
+use crate::device_status::DeviceStatus;
 use crate::error::{AppError, AppResult};
-use crate::modals::{AppModal, ModalLoadBehaviour};
+use crate::glyphs::glyphs;
+use crate::modals::{AppModal, ModalLoadBehaviour, ModalMetadata};
+use crate::send_queue::SendQueue;
+use crate::state::StateStore;
 use crate::theme::ThemeManager;
-use crate::types::{AppAction, KeyDebouncer, KeyPress, DEBOUNCE_DURATION};
+use crate::types::{AppAction, KeyDebouncer, KeyPress, WebSocketMode};
+use crate::ui::modals::about::AboutModal;
+use crate::ui::modals::contact_picker::ContactPickerModal;
+use crate::ui::modals::loading::LoadingModal;
+use crate::ui::modals::recent_errors::RecentErrorsModal;
+use crate::ui::modals::text_input::TextInputModal;
+use crate::ui::modals::ModalComponent;
 use crate::ui::notifications::{NotificationType, NotificationsView};
-use crate::ui::views::{ViewManager, ViewStateRequest};
-use crate::ui::ViewBase;
-use crate::TerminalConfig;
+use crate::ui::views::{TransitionOutcome, ViewManager, ViewStateRequest};
+use crate::ui::{device_status_line, render_key_hints, ViewBase};
+use crate::{AppArguments, AppOptions, TerminalConfig};
 
 pub type AppActionSender = mpsc::UnboundedSender<AppAction>;
-pub type AppContext = (Arc<HttpClient>, AppActionSender);
+
+/// Shared state handed to views and modal loaders, built once by the `App`.
+#[derive(Clone)]
+pub struct AppContext {
+    pub http: Arc<HttpClient>,
+    pub sender: AppActionSender,
+    pub options: Arc<AppOptions>,
+    pub contacts: Arc<ContactCache>,
+    pub state: Arc<StateStore>,
+    pub websocket: Arc<WebSocketStatus>,
+    pub send_queue: Arc<SendQueue>,
+}
+
+const REFRESH_PAGE_SIZE: u64 = 20;
+const MAX_REAUTH_ATTEMPTS: u32 = 2;
+const MAX_RECENT_ERRORS: usize = 50;
+const MIN_TERMINAL_WIDTH: u16 = 80;
+const MIN_TERMINAL_HEIGHT: u16 = 24;
+const STATE_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often the device status in the status bar is refreshed, on top of refreshing
+/// whenever the modem status changes or the device info view loads.
+const DEVICE_STATUS_INTERVAL: Duration = Duration::from_secs(60);
+const WEBSOCKET_BACKOFF_BASE: Duration = Duration::from_secs(1);
+const WEBSOCKET_BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+/// Exponential backoff for the given (1-based) attempt, capped at
+/// `WEBSOCKET_BACKOFF_MAX` with up to 25% jitter on top.
+fn websocket_backoff(attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16);
+    let delay = WEBSOCKET_BACKOFF_BASE
+        .saturating_mul(1 << exponent)
+        .min(WEBSOCKET_BACKOFF_MAX);
+
+    // No need for a proper RNG, the clock's sub-second nanos are random enough.
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    delay + delay.mul_f64(f64::from(nanos % 1000) / 4000.0)
+}
 
 pub struct App {
     view_manager: ViewManager,
@@ -32,7 +93,24 @@ pub struct App {
     message_receiver: mpsc::UnboundedReceiver<AppAction>,
     message_sender: mpsc::UnboundedSender<AppAction>,
     sms_client: Client,
-    websocket_enabled: bool,
+    context: AppContext,
+    last_state_save: Instant,
+    state_save_failing: bool,
+    device_status: Option<DeviceStatus>,
+    last_device_status: Instant,
+    websocket_max_retries: u32,
+    websocket_worker: Option<JoinHandle<()>>,
+    shutdown_timeout: Duration,
+    server_host: String,
+    server_uris: (String, Option<String>),
+    auth_configured: bool,
+    quiet: bool,
+    conversation_refresh: Option<Duration>,
+    last_input: Instant,
+    last_refresh: Instant,
+    client_arguments: AppArguments,
+    reauth_attempts: u32,
+    recent_errors: VecDeque<(DateTime<Utc>, String)>, // Oldest first, for the debug modal
     render_views: bool,
 
     #[cfg(feature = "sentry")]
@@ -44,18 +122,64 @@ impl App {
 
         // Create return channel and context.
         let (tx, rx) = mpsc::unbounded_channel();
-        let context: AppContext = (client.http_arc().map_err(|e| anyhow!("{e:?}"))?, tx.clone());
+        let (state, state_error) = StateStore::load();
+        let context = AppContext {
+            http: client.http_arc().map_err(|e| anyhow!("{e:?}"))?,
+            sender: tx.clone(),
+            send_queue: Arc::new(SendQueue::new(config.options.send_interval)),
+            options: Arc::new(config.options),
+            contacts: Arc::new(ContactCache::default()),
+            state: Arc::new(state),
+            websocket: Arc::new(WebSocketStatus::new(config.websocket)),
+        };
+
+        let mut notifications = NotificationsView::new();
+        for message in config.timestamp_errors {
+            notifications.add_notification(NotificationType::Failure {
+                title: "Invalid Timestamp Config".to_string(),
+                message,
+            });
+        }
+        for message in config.certificate_warnings {
+            notifications.add_notification(NotificationType::Failure {
+                title: "Certificate Skipped".to_string(),
+                message,
+            });
+        }
+        if let Some(message) = state_error {
+            notifications.add_notification(NotificationType::Failure {
+                title: "State Not Loaded".to_string(),
+                message,
+            });
+        }
 
         Ok(Self {
-            view_manager: ViewManager::new(context),
-            notifications: NotificationsView::new(),
+            view_manager: ViewManager::new(context.clone()),
+            notifications,
             current_modal: None,
-            theme_manager: ThemeManager::with_preset(config.theme),
-            key_debouncer: KeyDebouncer::new(DEBOUNCE_DURATION),
+            theme_manager: ThemeManager::with_preset(config.theme, config.action_colors),
+            key_debouncer: KeyDebouncer::new(config.key_debounce),
             message_receiver: rx,
             message_sender: tx,
             sms_client: client,
-            websocket_enabled: config.websocket,
+            context,
+            last_state_save: Instant::now(),
+            state_save_failing: false,
+            device_status: None,
+            last_device_status: Instant::now(),
+            websocket_max_retries: config.websocket_max_retries,
+            websocket_worker: None,
+            shutdown_timeout: config.shutdown_timeout,
+            server_host: config.server_host,
+            server_uris: config.server_uris,
+            auth_configured: config.auth_configured,
+            quiet: config.quiet,
+            conversation_refresh: config.conversation_refresh,
+            last_input: Instant::now(),
+            last_refresh: Instant::now(),
+            client_arguments: config.client_arguments,
+            reauth_attempts: 0,
+            recent_errors: VecDeque::new(),
             render_views: true,
 
             #[cfg(feature = "sentry")]
@@ -63,27 +187,44 @@ impl App {
         })
     }
 
+    /// Run until quit, then shut down. Shutdown also runs if the app stops with an error,
+    /// so state is saved and queued sends get their chance to finish either way.
     pub async fn run(
         mut self,
         mut terminal: DefaultTerminal,
         starting_view: ViewStateRequest,
     ) -> Result<()> {
-        if self.websocket_enabled {
+        let result = self.run_until_quit(&mut terminal, starting_view).await;
+        if let Err(e) = &result {
+            tracing::error!("Stopping after an error: {e}");
+        }
+        self.shutdown(&mut terminal).await;
+        result
+    }
+
+    async fn run_until_quit(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        starting_view: ViewStateRequest,
+    ) -> Result<()> {
+        if self.context.websocket.is_enabled() {
             self.start_sms_websocket().await?;
-        } else {
+        } else if !self.quiet {
             // Show a notification informing the user that their websocket
             // is disabled and therefore live updates will not work
-            let notification = NotificationType::Failure {
-                title: "WebSocket Disabled".to_string(),
-                message: "Live updates will not show!".to_string(),
+            let notification = NotificationType::WebSocketDisabled {
+                uri: TerminalConfig::websocket_uri(&self.client_arguments),
+                fell_back: false,
             };
             self.notifications.add_notification(notification);
         }
 
+        self.check_server_version();
+
         // If we're running a +sentry build, we're expecting to run in some managed env
         // where the sentry dsn is always set. Therefore, if it isn't show a warning.
         #[cfg(feature = "sentry")]
-        if !self.sentry_enabled {
+        if !self.sentry_enabled && !self.quiet {
             let notification = NotificationType::Failure {
                 title: "Sentry Inactive".to_string(),
                 message: "Sentry feature is compiled, but is not configured!".to_string(),
@@ -91,24 +232,63 @@ impl App {
             self.notifications.add_notification(notification);
         }
 
-        // Transition into starting state (which may be an error!)
-        self.transition_view(starting_view).await;
+        // Transition into starting state (which may be an error!), showing a
+        // connecting splash until it has loaded since nothing else is drawn yet.
+        let mut splash = LoadingModal::new(format!("Connecting to {}...", self.server_host));
+        let theme = Rc::clone(self.theme_manager.current());
+        let splash_screen = async {
+            loop {
+                let _ = terminal.draw(|frame| splash.render(frame, &theme));
+                if Self::escape_pressed() {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(80)).await;
+            }
+        };
+        self.transition_view_with(starting_view, splash_screen)
+            .await;
 
         let mut ticker = interval(Duration::from_millis(30));
-        loop {
+        'running: loop {
             // Process all actions from the channel
             while let Ok(action) = self.message_receiver.try_recv() {
                 if self.handle_app_action(action).await {
-                    return Ok(());
+                    break 'running;
                 }
             }
 
             terminal.draw(|frame| {
                 let theme = self.theme_manager.current();
 
-                // Views (bottom)
+                // Views can't lay out sensibly below a minimum size.
+                let area = frame.area();
+                if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+                    let message = Paragraph::new(format!(
+                        "Terminal too small ({}x{})\nResize to at least {MIN_TERMINAL_WIDTH}x{MIN_TERMINAL_HEIGHT}",
+                        area.width, area.height
+                    ))
+                    .style(theme.error_style)
+                    .centered()
+                    .wrap(Wrap { trim: true });
+                    frame.render_widget(message, area);
+                    return;
+                }
+
+                // Views (bottom), above the hint bar for the current view
                 if self.render_views {
-                    self.view_manager.render(frame, theme);
+                    let [views_area, status_area] =
+                        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+                    self.view_manager.render(frame, views_area, theme);
+
+                    // Device status on the right of the hint bar
+                    let status = device_status_line(self.device_status.as_ref(), theme);
+                    let [hints_area, device_area] = Layout::horizontal([
+                        Constraint::Min(0),
+                        Constraint::Length(status.width() as u16 + 1),
+                    ])
+                    .areas(status_area);
+                    render_key_hints(frame, hints_area, &self.view_manager.key_hints(), theme);
+                    frame.render_widget(status, device_area);
                 }
 
                 // Modals
@@ -117,42 +297,362 @@ impl App {
                 }
 
                 // Notifications (top)
-                self.notifications.render(frame, theme, ());
+                self.notifications.render(frame, area, theme, ());
             })?;
 
-            // Poll for key input
+            // Poll for key and mouse input
             while event::poll(Duration::from_millis(0))? {
-                if let event::Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Release {
-                        continue;
+                self.last_input = Instant::now();
+                let action = match event::read()? {
+                    event::Event::Key(key) if key.kind == KeyEventKind::Release => {
+                        self.key_debouncer.release(&KeyPress::from(key));
+                        None
                     }
-                    if let Some(action) = self.get_key_action(key).await {
-                        if self.handle_app_action(action).await {
-                            return Ok(());
-                        }
+                    event::Event::Key(key) => self.get_key_action(key).await,
+                    event::Event::Mouse(mouse) => self.get_mouse_action(mouse).await,
+                    _ => None,
+                };
+                if let Some(action) = action {
+                    if self.handle_app_action(action).await {
+                        break 'running;
                     }
                 }
             }
 
+            self.refresh_idle_conversation();
+            if self.last_state_save.elapsed() >= STATE_SAVE_INTERVAL {
+                self.save_state();
+            }
+            if self.last_device_status.elapsed() >= DEVICE_STATUS_INTERVAL {
+                self.refresh_device_status();
+            }
+
             // Yield back to runtime (for messages from websocket)
             ticker.tick().await;
         }
+        Ok(())
+    }
+
+    /// Save state, give queued sends a chance to finish, then stop the WebSocket worker.
+    /// Sends still running after the shutdown timeout are dropped with the runtime.
+    async fn shutdown(&mut self, terminal: &mut DefaultTerminal) {
+        tracing::info!("Shutting down");
+        self.save_state();
+
+        // Nothing new can be queued once the channel is closed, and what's left is dropped.
+        self.message_receiver.close();
+        while self.message_receiver.try_recv().is_ok() {}
+
+        if self.context.send_queue.is_busy() && !self.shutdown_timeout.is_zero() {
+            let mut modal = LoadingModal::new("Sending queued messages...");
+            let theme = Rc::clone(self.theme_manager.current());
+            let _ = terminal.draw(|frame| modal.render(frame, &theme));
+
+            let flushed =
+                tokio::time::timeout(self.shutdown_timeout, self.context.send_queue.flush())
+                    .await
+                    .is_ok();
+            if !flushed {
+                tracing::warn!(
+                    "Queued sends didn't finish within {}s, exiting anyway",
+                    self.shutdown_timeout.as_secs()
+                );
+            }
+        }
+
+        if let Some(worker) = self.websocket_worker.take() {
+            worker.abort();
+        }
     }
 
     async fn transition_view(&mut self, request: ViewStateRequest) {
-        self.view_manager.transition_to(request).await;
+        // Allow slow loads to be cancelled with Esc, staying on the current view.
+        self.transition_view_with(request, Self::wait_for_escape())
+            .await;
+    }
+
+    async fn transition_view_with(
+        &mut self,
+        request: ViewStateRequest,
+        cancel: impl Future<Output = ()>,
+    ) {
+        tracing::debug!("Transitioning to {} view", request.kind());
+        let is_error = matches!(request, ViewStateRequest::Error { .. });
+        match self.view_manager.transition_to(request, cancel).await {
+            TransitionOutcome::Loaded if !is_error => self.reauth_attempts = 0,
+            TransitionOutcome::Failed(message) => self.record_error(message),
+            TransitionOutcome::Unauthorized(request, message) => {
+                self.record_error(message);
+                self.prompt_reauthenticate(request);
+            }
+            TransitionOutcome::Cancelled => {
+                let notification = NotificationType::Failure {
+                    title: "Loading Cancelled".to_string(),
+                    message: "Stayed on the current view.".to_string(),
+                };
+                self.notifications.add_notification(notification);
+            }
+            _ => {}
+        }
         self.key_debouncer.reset();
 
         let _ = crossterm::execute!(
             std::io::stdout(),
             crossterm::terminal::SetTitle(format!(
-                "SMS Terminal v{} ｜ {}",
+                "SMS Terminal v{} {} {}",
                 crate::FEATURE_VERSION,
+                glyphs().separator,
                 self.view_manager
             )),
         );
     }
 
+    /// Save any state changes, warning once (until it next succeeds) if it can't be written.
+    fn save_state(&mut self) {
+        self.last_state_save = Instant::now();
+        match self.context.state.save_if_dirty() {
+            Ok(()) => self.state_save_failing = false,
+            Err(e) if !self.state_save_failing => {
+                tracing::warn!("Failed to save state: {e}");
+                self.state_save_failing = true;
+                self.notifications
+                    .add_notification(NotificationType::Failure {
+                        title: "State Not Saved".to_string(),
+                        message: e.to_string(),
+                    });
+            }
+            Err(_) => {}
+        }
+    }
+
+    /// Without a connected WebSocket new messages never arrive, so once idle
+    /// periodically fetch the newest page of the open conversation and merge it.
+    fn refresh_idle_conversation(&mut self) {
+        let Some(interval) = self.conversation_refresh else {
+            return;
+        };
+        if self.context.websocket.is_connected()
+            || self.current_modal.is_some()
+            || self.last_input.elapsed() < interval
+            || self.last_refresh.elapsed() < interval
+        {
+            return;
+        }
+        let Some(phone_number) = self.view_manager.messages_phone_number() else {
+            return;
+        };
+        let Ok(http) = self.sms_client.http_arc() else {
+            return;
+        };
+
+        self.last_refresh = Instant::now();
+        let phone_number = phone_number.to_string();
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            let pagination = HttpPaginationOptions::default().with_limit(REFRESH_PAGE_SIZE);
+            if let Ok(messages) = http.get_messages(&phone_number, Some(pagination)).await {
+                let _ = sender.send(AppAction::MergeMessages {
+                    phone_number,
+                    messages,
+                });
+            }
+        });
+    }
+
+    /// Compare the server's version against the minimum this client supports, blocking
+    /// with an error if it's too old. A failed request is left to the starting view to
+    /// report, and a version that can't be parsed only warns since it may be fine.
+    fn check_server_version(&self) {
+        let Ok(http) = self.sms_client.http_arc() else {
+            return;
+        };
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            let Ok(info) = http.get_device_info().await else {
+                return;
+            };
+            let _ = sender.send(AppAction::UpdateDeviceStatus(DeviceStatus::from(&info)));
+
+            let minimum = format_version(MIN_SERVER_VERSION);
+            match ServerVersionCheck::check(&info.version) {
+                ServerVersionCheck::Supported => {
+                    tracing::info!("Server version {}", info.version);
+                }
+                ServerVersionCheck::Unsupported => {
+                    let _ = sender.send(AppAction::ShowError {
+                        message: format!(
+                            "Server version {} is not supported, {minimum} or newer is required",
+                            info.version
+                        ),
+                        dismissible: false,
+                    });
+                }
+                ServerVersionCheck::Unknown => {
+                    tracing::warn!("Unrecognised server version {:?}", info.version);
+                    let notification = NotificationType::Failure {
+                        title: "Unknown Server Version".to_string(),
+                        message: format!(
+                            "Couldn't read version '{}', {minimum} or newer is required",
+                            info.version
+                        ),
+                    };
+                    let _ = sender.send(AppAction::ShowNotification(notification));
+                }
+            }
+        });
+    }
+
+    /// Fetch the device info in the background to update the status bar, leaving the
+    /// last known status in place if the request fails.
+    fn refresh_device_status(&mut self) {
+        self.last_device_status = Instant::now();
+        let Ok(http) = self.sms_client.http_arc() else {
+            return;
+        };
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            match http.get_device_info().await {
+                Ok(info) => {
+                    let _ = sender.send(AppAction::UpdateDeviceStatus(DeviceStatus::from(&info)));
+                }
+                Err(e) => tracing::debug!("Failed to refresh device status: {e}"),
+            }
+        });
+    }
+
+    /// Name a number from outside the phonebook, eg: from a new message notification.
+    fn set_friendly_name(&mut self, phone_number: String, friendly_name: String) {
+        self.context
+            .contacts
+            .set_friendly_name(&phone_number, Some(friendly_name.clone()));
+        self.notifications
+            .set_friendly_name(&phone_number, &friendly_name);
+
+        let Ok(http) = self.sms_client.http_arc() else {
+            return;
+        };
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            if http
+                .set_friendly_name(&phone_number, Some(friendly_name))
+                .await
+                .is_err()
+            {
+                let notification = NotificationType::Failure {
+                    title: "Edit Failed".to_string(),
+                    message: format!("Failed to change friendly name for {phone_number}"),
+                };
+                let _ = sender.send(AppAction::ShowNotification(notification));
+            }
+        });
+    }
+
+    /// Keep an error for the recent errors modal, dropping the oldest once full.
+    fn record_error(&mut self, message: String) {
+        if self.recent_errors.len() >= MAX_RECENT_ERRORS {
+            self.recent_errors.pop_front();
+        }
+        self.recent_errors.push_back((Utc::now(), message));
+    }
+
+    /// Ask for a new auth token after the server rejected the current one. After too many
+    /// attempts the (non-dismissible) error view that is already showing is left as is.
+    /// If no token was configured at all, the error is replaced with how to set one.
+    fn prompt_reauthenticate(&mut self, request: ViewStateRequest) {
+        if self.reauth_attempts >= MAX_REAUTH_ATTEMPTS {
+            return;
+        }
+        self.reauth_attempts += 1;
+
+        let (title, prompt) = if self.auth_configured {
+            (
+                "Authentication Failed",
+                "The server rejected the auth token, enter a new one:",
+            )
+        } else {
+            let message = format!(
+                "The server requires authentication, but no auth token is configured.\n\
+                 Start with --auth <token>, or add auth = \"<token>\" to {}",
+                AppArguments::config_path().display()
+            );
+            let _ = self.message_sender.send(AppAction::SetViewState {
+                state: ViewStateRequest::Error {
+                    message,
+                    dismissible: true,
+                    retry: Some(Box::new(request.clone())),
+                },
+                dismiss_modal: false,
+            });
+            (
+                "Authentication Required",
+                "The server requires an auth token, enter one:",
+            )
+        };
+
+        let modal = AppModal::new("reauthenticate", TextInputModal::new(title, prompt))
+            .with_metadata(ModalMetadata::Reauthenticate(Box::new(request)));
+        self.set_modal(Some(modal));
+    }
+
+    /// Rebuild the client with a new auth token, and re-point the view context at it.
+    /// Views already created keep the old client, so the failed request is retried
+    /// by transitioning to a fresh view. The WebSocket keeps its original connection.
+    fn reauthenticate(&mut self, token: String) -> AppResult<()> {
+        let mut arguments = self.client_arguments.clone();
+        arguments.auth = Some(token);
+        self.rebuild_client(arguments)?;
+        self.auth_configured = true;
+        Ok(())
+    }
+
+    /// Start the WebSocket after running without it. The client was created without
+    /// a WebSocket config, so it's rebuilt with one first.
+    async fn enable_websocket(&mut self) -> AppResult<()> {
+        let mut arguments = self.client_arguments.clone();
+        arguments.ws_enabled = Some(true);
+        arguments.ws_mode = Some(WebSocketMode::On);
+        self.rebuild_client(arguments)?;
+
+        self.server_uris = TerminalConfig::server_uris(&self.client_arguments);
+        self.context.websocket.enable();
+        self.start_sms_websocket().await
+    }
+
+    fn rebuild_client(&mut self, arguments: AppArguments) -> AppResult<()> {
+        // Certificate warnings were already shown at startup.
+        let (config, _) = TerminalConfig::create_sms_config(&arguments)
+            .map_err(|e| AppError::Config(e.to_string()))?;
+        let client = Client::new(config).map_err(|e| AppError::Config(e.to_string()))?;
+        let http = client
+            .http_arc()
+            .map_err(|e| AppError::Config(format!("{e:?}")))?;
+
+        self.context.http = http.clone();
+        self.view_manager.set_http_client(http);
+        self.sms_client = client;
+        self.client_arguments = arguments;
+        Ok(())
+    }
+
+    /// Drain pending input, returning true if Esc was pressed.
+    fn escape_pressed() -> bool {
+        while event::poll(Duration::from_millis(0)).unwrap_or(false) {
+            if let Ok(event::Event::Key(key)) = event::read() {
+                if key.code == KeyCode::Esc && key.kind != KeyEventKind::Release {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Resolves once Esc is pressed, discarding any other key input.
+    async fn wait_for_escape() {
+        while !Self::escape_pressed() {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+        }
+    }
+
     async fn handle_app_action(&mut self, action: AppAction) -> bool {
         match action {
             AppAction::SetViewState {
@@ -167,14 +667,70 @@ impl App {
                 self.transition_view(state).await;
             }
             AppAction::SetModal(modal) => self.set_modal(modal),
+            AppAction::ReloadModal => {
+                // Setting the same modal again runs its loader.
+                if let Some(modal) = self.current_modal.take() {
+                    self.set_modal(Some(modal));
+                }
+            }
+            AppAction::UpdateDeviceStatus(status) => self.device_status = Some(status),
+            AppAction::RefreshDeviceStatus => self.refresh_device_status(),
             AppAction::Exit => return true,
+            AppAction::MergeMessages {
+                phone_number,
+                messages,
+            } => {
+                let merged = self
+                    .view_manager
+                    .merge_messages(&phone_number, messages, REFRESH_PAGE_SIZE)
+                    .await;
+                if let Err(e) = merged {
+                    tracing::warn!("Failed to reload refreshed conversation: {e}");
+                }
+            }
+            AppAction::MessagesPage(page) => {
+                if let Some(action) = self.view_manager.apply_messages_page(page) {
+                    let _ = self.message_sender.send(action);
+                }
+            }
+            AppAction::AddPendingMessage {
+                phone_number,
+                pending_id,
+                content,
+                parts,
+            } => self
+                .view_manager
+                .add_pending_message(&phone_number, pending_id, &content, parts),
+            AppAction::ResolvePendingMessage {
+                phone_number,
+                pending_id,
+                message_id,
+            } => {
+                self.view_manager
+                    .resolve_pending_message(&phone_number, pending_id, message_id);
+            }
             AppAction::HandleMessage(sms_message) => {
+                tracing::debug!(
+                    "Received {} message for {}",
+                    if sms_message.is_outgoing {
+                        "outgoing"
+                    } else {
+                        "incoming"
+                    },
+                    sms_message.phone_number
+                );
+                self.context.contacts.touch(&sms_message.phone_number);
+
                 // Try to add the incoming message to the current view
                 let show_notification = !self.view_manager.try_add_message(&sms_message);
 
                 // Show incoming notification if not suppressed by view
                 if show_notification && !sms_message.is_outgoing {
                     let notification = NotificationType::IncomingMessage {
+                        friendly_name: self
+                            .context
+                            .contacts
+                            .friendly_name(&sms_message.phone_number),
                         phone: sms_message.phone_number.clone(),
                         content: sms_message.message_content,
                     };
@@ -183,18 +739,55 @@ impl App {
             }
             AppAction::DeliveryFailure(_) => unimplemented!("Oops!"),
             AppAction::ShowNotification(notification) => {
+                if let NotificationType::Failure { title, message } = &notification {
+                    tracing::warn!("{title}: {message}");
+                }
+                if let NotificationType::WebSocketConnectionUpdate(kind) = &notification {
+                    self.context
+                        .websocket
+                        .set_connected(matches!(kind, WebsocketReconnectionKind::Connected));
+                }
                 self.notifications.add_notification(notification);
             }
+            AppAction::EnableWebSocket => {
+                if let Err(e) = self.enable_websocket().await {
+                    tracing::error!("Failed to enable WebSocket: {e}");
+                    let notification = NotificationType::Failure {
+                        title: "WebSocket Failed".to_string(),
+                        message: e.to_string(),
+                    };
+                    self.notifications.add_notification(notification);
+                }
+            }
+            AppAction::Reauthenticate { token, retry } => match self.reauthenticate(token) {
+                Ok(()) => self.transition_view(*retry).await,
+                Err(e) => {
+                    tracing::error!("Failed to reauthenticate: {e}");
+                    self.transition_view(ViewStateRequest::from(e)).await;
+                }
+            },
+            AppAction::SetFriendlyName {
+                phone_number,
+                friendly_name,
+            } => self.set_friendly_name(phone_number, friendly_name),
+            AppAction::ShowAbout => {
+                let modal = AppModal::new("about", self.about_modal());
+                self.set_modal(Some(modal));
+            }
             AppAction::ShowError {
                 message,
                 dismissible,
             } => {
+                tracing::error!("{message}");
+                self.record_error(message.clone());
+
                 // If another error is being displayed, only overwrite it if
                 // that one is dismissable but this one isn't. Otherwise, ignore.
                 if self.view_manager.should_show_error(dismissible) {
                     self.transition_view(ViewStateRequest::Error {
                         message,
                         dismissible,
+                        retry: None,
                     })
                     .await;
                 }
@@ -206,7 +799,10 @@ impl App {
 
     async fn get_key_action(&mut self, key: KeyEvent) -> Option<AppAction> {
         let key_press = KeyPress::from(key);
-        if !self.key_debouncer.should_process(&key_press) {
+        if !self
+            .key_debouncer
+            .should_process(&key_press, Instant::now())
+        {
             return None;
         }
 
@@ -230,6 +826,52 @@ impl App {
             return response;
         }
 
+        // About, from any view (F1 dismisses notifications while they're showing)
+        if key.code == KeyCode::F(1) && self.notifications.is_empty() {
+            return Some(AppAction::ShowAbout);
+        }
+
+        // Reload the current view, retrying the failed request from an error
+        if key.code == KeyCode::F(5) {
+            self.view_manager.invalidate_cache(None);
+            let request = match self.view_manager.current_request() {
+                ViewStateRequest::Error {
+                    retry: Some(retry), ..
+                } => *retry,
+                request => request,
+            };
+            return Some(AppAction::SetViewState {
+                state: request,
+                dismiss_modal: false,
+            });
+        }
+
+        // Recent errors, from any view (debug only)
+        if key.code == KeyCode::F(12) && self.context.options.debug {
+            let errors = self
+                .recent_errors
+                .iter()
+                .rev()
+                .map(|(time, message)| {
+                    (
+                        self.context.options.timestamps.date_time(*time),
+                        message.clone(),
+                    )
+                })
+                .collect();
+            let modal = AppModal::new("recent_errors", RecentErrorsModal::new(errors));
+            return Some(AppAction::SetModal(Some(modal)));
+        }
+
+        // Go to number, from any view
+        if key.code == KeyCode::Char('g') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            let modal = AppModal::new(
+                "go_to_number",
+                ContactPickerModal::new(self.context.contacts.fresh_contacts()),
+            );
+            return Some(AppAction::SetModal(Some(modal)));
+        }
+
         // Handle notification interactions
         if let Some(response) = self.notifications.handle_key(key, ()).await {
             return Some(response);
@@ -239,6 +881,96 @@ impl App {
         self.view_manager.handle_key(key).await
     }
 
+    fn about_modal(&self) -> AboutModal {
+        #[cfg(feature = "sentry")]
+        let sentry = if self.sentry_enabled {
+            "compiled, active"
+        } else {
+            "compiled, not configured"
+        };
+        #[cfg(not(feature = "sentry"))]
+        let sentry = "not compiled";
+
+        let preset = format!("{:?}", self.theme_manager.current_preset()).to_lowercase();
+        let theme = format!(
+            "{preset} ({})",
+            if self.theme_manager.modify_background() {
+                "background fill"
+            } else {
+                "no background fill"
+            }
+        );
+
+        AboutModal::new(vec![
+            ("Version", crate::FEATURE_VERSION.to_string()),
+            ("Host", self.server_host.clone()),
+            ("HTTP URI", self.server_uris.0.clone()),
+            (
+                "WebSocket URI",
+                self.server_uris
+                    .1
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            ("WebSocket", self.context.websocket.label().to_string()),
+            (
+                "Auth",
+                if self.auth_configured {
+                    "<redacted>"
+                } else {
+                    "none"
+                }
+                .to_string(),
+            ),
+            ("Sentry", sentry.to_string()),
+            (
+                "Timezone",
+                self.context.options.timestamps.timezone.to_string(),
+            ),
+            ("Theme", theme),
+            (
+                "State File",
+                if self.context.state.is_in_memory() {
+                    "in memory only".to_string()
+                } else {
+                    StateStore::path().display().to_string()
+                },
+            ),
+        ])
+    }
+
+    async fn get_mouse_action(&mut self, mouse: MouseEvent) -> Option<AppAction> {
+        // Debounce the wheel like arrow keys, so fast scrolling can't flood pagination.
+        let wheel_key = match mouse.kind {
+            MouseEventKind::ScrollDown => Some(KeyCode::Down),
+            MouseEventKind::ScrollUp => Some(KeyCode::Up),
+            _ => None,
+        };
+        if let Some(code) = wheel_key {
+            let key_press = KeyPress {
+                code,
+                modifiers: mouse.modifiers,
+            };
+            if !self
+                .key_debouncer
+                .should_process(&key_press, Instant::now())
+            {
+                return None;
+            }
+        }
+
+        // Modals capture the mouse, like they do keys.
+        if let Some(modal) = &mut self.current_modal {
+            let response = self.view_manager.handle_modal_mouse(modal, mouse);
+            if response.is_some() {
+                self.set_modal(None);
+            }
+            return response;
+        }
+
+        self.view_manager.handle_mouse(mouse).await
+    }
+
     fn set_modal(&mut self, modal: Option<AppModal>) {
         // Allow the modal to determine if background views should render.
         self.render_views = modal.as_ref().is_none_or(AppModal::should_render_views);
@@ -247,14 +979,7 @@ impl App {
             // Call modal loader, which can take the current AppContext for async loading.
             // This is to ensure that the render + async loop is never blocked.
             if let ModalLoadBehaviour::Function(cb) = modal.load() {
-                // We can use expect here since the client is already checked in new.
-                // This just prevents us having to propagate a Result that will never Err.
-                let http = self
-                    .sms_client
-                    .http_arc()
-                    .expect("Missing HttpClient within SMS Client!");
-
-                let (action, should_block) = cb((http, self.message_sender.clone()));
+                let (action, should_block) = cb(self.context.clone());
                 if let Some(action) = action {
                     let _ = self.message_sender.send(action);
                 }
@@ -267,8 +992,9 @@ impl App {
         self.current_modal = modal;
     }
 
-    async fn start_sms_websocket(&self) -> AppResult<()> {
+    async fn start_sms_websocket(&mut self) -> AppResult<()> {
         let ws_sender = self.message_sender.clone();
+        let ws_status = self.context.websocket.clone();
         self.sms_client
             .on_message_simple(move |message| match message {
                 WebsocketEvent::Server(Event::IncomingMessage(sms))
@@ -278,8 +1004,13 @@ impl App {
                 WebsocketEvent::Server(Event::ModemStatusUpdate { previous, current }) => {
                     let notification = NotificationType::OnlineStatus { previous, current };
                     let _ = ws_sender.send(AppAction::ShowNotification(notification));
+                    let _ = ws_sender.send(AppAction::RefreshDeviceStatus);
                 }
                 WebsocketEvent::Reconnection(kind) => {
+                    tracing::info!("WebSocket connection update: {kind:?}");
+                    // Set straight away rather than when the notification is handled,
+                    // so the worker below knows whether it ever connected.
+                    ws_status.set_connected(matches!(kind, WebsocketReconnectionKind::Connected));
                     let notification = NotificationType::WebSocketConnectionUpdate(kind);
                     let _ = ws_sender.send(AppAction::ShowNotification(notification));
                 }
@@ -287,20 +1018,67 @@ impl App {
             })
             .await?;
 
-        // Create websocket worker task.
+        // Create websocket worker task, which reconnects with backoff until
+        // too many consecutive attempts have failed.
         let client = self.sms_client.clone();
         let task_sender = self.message_sender.clone();
-        tokio::spawn(async move {
-            // Handle early termination or errors on starting.
-            let (message, dismissible) = match client.start_blocking_websocket().await {
-                Ok(()) => ("The WebSocket has been terminated!".to_string(), true),
-                Err(e) => (e.to_string(), false),
-            };
-            let _ = task_sender.send(AppAction::ShowError {
-                message,
-                dismissible,
-            });
+        let max_retries = self.websocket_max_retries;
+        let status = self.context.websocket.clone();
+        let quiet = self.quiet;
+        let uri = TerminalConfig::websocket_uri(&self.client_arguments);
+        tracing::info!("Starting WebSocket for {uri}");
+        let worker = tokio::spawn(async move {
+            let mut failures = 0;
+            loop {
+                let started = Instant::now();
+                let result = client.start_blocking_websocket().await;
+                match &result {
+                    Ok(()) => tracing::warn!("WebSocket closed"),
+                    Err(e) => tracing::warn!("WebSocket failed: {e}"),
+                }
+
+                // In auto mode, fall back to running without live updates
+                // if the first connection couldn't be made.
+                if status.should_fall_back() {
+                    tracing::info!("WebSocket couldn't connect, continuing without it");
+                    status.fall_back();
+                    if !quiet {
+                        let notification = NotificationType::WebSocketDisabled {
+                            uri,
+                            fell_back: true,
+                        };
+                        let _ = task_sender.send(AppAction::ShowNotification(notification));
+                    }
+                    return;
+                }
+
+                // A connection that stayed up for a while resets the failure count.
+                if started.elapsed() >= WEBSOCKET_BACKOFF_MAX {
+                    failures = 0;
+                }
+                failures += 1;
+
+                if failures > max_retries {
+                    tracing::error!("WebSocket gave up after {max_retries} retries");
+                    let (message, dismissible) = match result {
+                        Ok(()) => ("The WebSocket has been terminated!".to_string(), true),
+                        Err(e) => (e.to_string(), false),
+                    };
+                    let _ = task_sender.send(AppAction::ShowError {
+                        message,
+                        dismissible,
+                    });
+                    return;
+                }
+
+                let notification = NotificationType::WebSocketConnectionUpdate(
+                    WebsocketReconnectionKind::Disconnected(true),
+                );
+                let _ = task_sender.send(AppAction::ShowNotification(notification));
+                tokio::time::sleep(websocket_backoff(failures)).await;
+            }
         });
+        self.websocket_worker = Some(worker);
 
         Ok(())
     }
@@ -0,0 +1,78 @@
+use std::path::Path;
+use x509_parser::pem::Pem;
+use x509_parser::prelude::{ASN1Time, FromDer, X509Certificate};
+
+use crate::error::{AppError, AppResult};
+
+/// Check an SSL certificate file before handing it to the TLS config, which only
+/// reports a generic failure. Each certificate in a PEM bundle is checked, so an
+/// unreadable or malformed certificate gets its own message. Expired or not yet valid
+/// certificates in a bundle are returned as warnings, failing only if none are valid.
+pub fn validate_certificate(path: &Path) -> AppResult<Vec<String>> {
+    let fail =
+        |reason: String| AppError::Config(format!("SSL certificate {}: {reason}", path.display()));
+
+    let data = std::fs::read(path).map_err(|e| fail(format!("couldn't be read, {e}")))?;
+
+    // PEM files may hold a chain, otherwise expect a single DER certificate.
+    if data.trim_ascii_start().starts_with(b"-----BEGIN") {
+        let mut found = false;
+        let mut invalid = Vec::new();
+        for pem in Pem::iter_from_buffer(&data) {
+            let pem = pem.map_err(|e| fail(format!("is malformed PEM, {e}")))?;
+            if pem.label != "CERTIFICATE" {
+                continue;
+            }
+            let certificate = pem
+                .parse_x509()
+                .map_err(|e| fail(format!("is not a valid certificate, {e}")))?;
+            match check_validity(&certificate) {
+                Ok(()) => found = true,
+                Err(reason) => invalid.push(reason),
+            }
+        }
+        if !found {
+            return Err(fail(if invalid.is_empty() {
+                "contains no certificates".to_string()
+            } else {
+                format!("has no valid certificates, {}", invalid.join(", "))
+            }));
+        }
+
+        let warnings = invalid
+            .into_iter()
+            .map(|reason| {
+                format!(
+                    "SSL certificate {}: skipped a certificate that {reason}",
+                    path.display()
+                )
+            })
+            .collect();
+        Ok(warnings)
+    } else {
+        let (_, certificate) = X509Certificate::from_der(&data)
+            .map_err(|e| fail(format!("is not a valid PEM or DER certificate, {e}")))?;
+        check_validity(&certificate).map_err(fail)?;
+        Ok(Vec::new())
+    }
+}
+
+fn check_validity(certificate: &X509Certificate) -> Result<(), String> {
+    let validity = certificate.validity();
+    let now = ASN1Time::now();
+    if validity.not_after < now {
+        return Err(format!(
+            "expired on {} ({})",
+            validity.not_after,
+            certificate.subject()
+        ));
+    }
+    if validity.not_before > now {
+        return Err(format!(
+            "is not valid until {} ({})",
+            validity.not_before,
+            certificate.subject()
+        ));
+    }
+    Ok(())
+}
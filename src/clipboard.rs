@@ -0,0 +1,8 @@
+use crossterm::clipboard::CopyToClipboard;
+use std::io;
+
+/// Copy text to the system clipboard through the terminal (OSC 52), which also
+/// works over SSH. Terminals without support silently ignore it.
+pub fn copy(text: &str) -> io::Result<()> {
+    crossterm::execute!(io::stdout(), CopyToClipboard::to_clipboard_from(text))
+}
@@ -0,0 +1,190 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::types::WebSocketMode;
+
+/// The WebSocket's connection state, tracked by the `App` from its connection
+/// updates and shared with views through the `AppContext`.
+#[derive(Debug, Default)]
+pub struct WebSocketStatus {
+    mode: RwLock<WebSocketMode>,
+    connected: RwLock<Option<bool>>, // None until the first connection update
+    has_connected: AtomicBool,
+    fell_back: AtomicBool, // Auto mode gave up after failing to connect
+}
+impl WebSocketStatus {
+    pub fn new(mode: WebSocketMode) -> Self {
+        Self {
+            mode: RwLock::new(mode),
+            connected: RwLock::new(None),
+            has_connected: AtomicBool::new(false),
+            fell_back: AtomicBool::new(false),
+        }
+    }
+
+    fn mode(&self) -> WebSocketMode {
+        self.mode.read().map_or(WebSocketMode::Off, |mode| *mode)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.mode() != WebSocketMode::Off && !self.fell_back.load(Ordering::Relaxed)
+    }
+
+    /// Turn the WebSocket on after starting without it, eg: from the disabled warning.
+    pub fn enable(&self) {
+        if let Ok(mut mode) = self.mode.write() {
+            *mode = WebSocketMode::On;
+        }
+        self.fell_back.store(false, Ordering::Relaxed);
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+            .read()
+            .is_ok_and(|connected| *connected == Some(true))
+    }
+
+    pub fn set_connected(&self, connected: bool) {
+        if connected {
+            self.has_connected.store(true, Ordering::Relaxed);
+        }
+        if let Ok(mut inner) = self.connected.write() {
+            *inner = Some(connected);
+        }
+    }
+
+    /// In auto mode, a first connection that fails means the server likely doesn't
+    /// have the WebSocket available, so it's given up on rather than retried.
+    pub fn should_fall_back(&self) -> bool {
+        self.mode() == WebSocketMode::Auto && !self.has_connected.load(Ordering::Relaxed)
+    }
+
+    pub fn fall_back(&self) {
+        self.fell_back.store(true, Ordering::Relaxed);
+    }
+
+    /// A short description of the state, eg: for the about modal.
+    pub fn label(&self) -> &'static str {
+        if self.fell_back.load(Ordering::Relaxed) {
+            return "unavailable";
+        }
+        if !self.is_enabled() {
+            return "disabled";
+        }
+        match self.connected.read().ok().and_then(|connected| *connected) {
+            None => "connecting",
+            Some(true) => "connected",
+            Some(false) => "disconnected",
+        }
+    }
+}
+
+/// The oldest server version this client works with, as (major, minor, patch).
+/// Raise it when the app starts relying on features from a newer server.
+pub const MIN_SERVER_VERSION: (u64, u64, u64) = (1, 0, 0);
+
+/// The result of comparing the server's reported version against `MIN_SERVER_VERSION`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerVersionCheck {
+    Supported,
+    Unsupported,
+    Unknown, // The version couldn't be parsed
+}
+impl ServerVersionCheck {
+    pub fn check(version: &str) -> Self {
+        match parse_version(version) {
+            Some(version) if version >= MIN_SERVER_VERSION => Self::Supported,
+            Some(_) => Self::Unsupported,
+            None => Self::Unknown,
+        }
+    }
+}
+
+/// Parse a version like "1.2.3", "v1.2" or "1.2.3-beta+abc" into (major, minor, patch),
+/// with missing minor or patch numbers read as 0. Pre-release and build suffixes are ignored.
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    let core = version.split(['-', '+']).next()?;
+
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+pub fn format_version((major, minor, patch): (u64, u64, u64)) -> String {
+    format!("{major}.{minor}.{patch}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_versions() {
+        let cases = [
+            ("1.2.3", Some((1, 2, 3))),
+            ("  1.2.3\n", Some((1, 2, 3))),
+            ("v1.2.3", Some((1, 2, 3))),
+            ("V10.20.30", Some((10, 20, 30))),
+            ("1.2", Some((1, 2, 0))),
+            ("1", Some((1, 0, 0))),
+            ("1.2.3-beta", Some((1, 2, 3))),
+            ("1.2.3-rc.1+build.5", Some((1, 2, 3))),
+            ("1.2+abc", Some((1, 2, 0))),
+            ("", None),
+            ("v", None),
+            ("1.2.3.4", None),
+            ("1..3", None),
+            ("1.2.", None),
+            ("one.two", None),
+            ("1.-2.3", None),
+            ("-1.2.3", None),
+            ("1.2.x", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_version(input), expected, "parsing {input:?}");
+        }
+    }
+
+    #[test]
+    fn checks_against_minimum() {
+        let (major, minor, patch) = MIN_SERVER_VERSION;
+        let cases = [
+            (
+                format_version(MIN_SERVER_VERSION),
+                ServerVersionCheck::Supported,
+            ),
+            (format!("{}.0.0", major + 1), ServerVersionCheck::Supported),
+            (
+                format!("{major}.{minor}.{}-beta", patch + 1),
+                ServerVersionCheck::Supported,
+            ),
+            ("0.9.9".to_string(), ServerVersionCheck::Unsupported),
+            ("0.99".to_string(), ServerVersionCheck::Unsupported),
+            ("unknown".to_string(), ServerVersionCheck::Unknown),
+            (String::new(), ServerVersionCheck::Unknown),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                ServerVersionCheck::check(&input),
+                expected,
+                "checking {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn formats_versions() {
+        assert_eq!(format_version((1, 0, 0)), "1.0.0");
+        assert_eq!(
+            parse_version(&format_version((3, 14, 159))),
+            Some((3, 14, 159))
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use sms_client::error::ClientError;
+use sms_client::http::HttpClient;
+use sms_client::types::http::{HttpPaginationOptions, LatestNumberFriendlyNamePair};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::error::AppResult;
+
+/// The most recent contacts fetched into the cache.
+pub const CONTACT_CACHE_LIMIT: u64 = 50;
+
+/// How long a fetched contact list is considered fresh for.
+const CONTACT_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Normalize a phone number for display by dropping common separators (spaces, dashes,
+/// dots and brackets), or None if what remains isn't an optional `+` followed by 3 to 15
+/// digits (the E.164 maximum).
+pub fn normalize_phone_number(number: &str) -> Option<String> {
+    let normalized: String = number
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect();
+
+    let digits = normalized.strip_prefix('+').unwrap_or(&normalized);
+    let valid = (3..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit());
+    valid.then_some(normalized)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contact {
+    pub number: String,
+    pub friendly_name: Option<String>,
+}
+impl Contact {
+    /// Does the number or friendly name contain the (case-insensitive) query.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.number.to_lowercase().contains(&query)
+            || self
+                .friendly_name
+                .as_ref()
+                .is_some_and(|name| name.to_lowercase().contains(&query))
+    }
+}
+impl From<LatestNumberFriendlyNamePair> for Contact {
+    fn from(pair: LatestNumberFriendlyNamePair) -> Self {
+        Self {
+            number: pair.number,
+            friendly_name: pair.friendly_name,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ContactCacheInner {
+    names: HashMap<String, Option<String>>, // number -> friendly name
+    recency: Vec<String>,                   // numbers, most recent first
+    fetched_at: Option<Instant>,
+}
+
+/// App level cache of recent contacts, shared through the `AppContext` so
+/// that views agree on friendly names and don't refetch `get_latest_numbers`.
+#[derive(Debug, Default)]
+pub struct ContactCache {
+    inner: RwLock<ContactCacheInner>,
+}
+impl ContactCache {
+    /// Get the cached contacts (most recent first), or None if never fetched.
+    pub fn contacts(&self) -> Option<Vec<Contact>> {
+        let inner = self.inner.read().ok()?;
+        inner.fetched_at?;
+
+        let contacts = inner
+            .recency
+            .iter()
+            .map(|number| Contact {
+                number: number.clone(),
+                friendly_name: inner.names.get(number).cloned().flatten(),
+            })
+            .collect();
+        Some(contacts)
+    }
+
+    /// Get the cached contacts only if they are still fresh.
+    pub fn fresh_contacts(&self) -> Option<Vec<Contact>> {
+        if self.is_stale() {
+            None
+        } else {
+            self.contacts()
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.inner
+            .read()
+            .ok()
+            .and_then(|inner| inner.fetched_at)
+            .is_none_or(|fetched_at| fetched_at.elapsed() > CONTACT_CACHE_TTL)
+    }
+
+    /// Fetch the latest contacts from the server, replacing the cache.
+    pub async fn fetch(&self, http: &HttpClient) -> AppResult<Vec<Contact>> {
+        let pagination = HttpPaginationOptions::default().with_limit(CONTACT_CACHE_LIMIT);
+        let contacts: Vec<Contact> = http
+            .get_latest_numbers(Some(pagination))
+            .await
+            .map_err(ClientError::from)?
+            .into_iter()
+            .map(Contact::from)
+            .collect();
+
+        self.replace(&contacts);
+        Ok(contacts)
+    }
+
+    fn replace(&self, contacts: &[Contact]) {
+        let Ok(mut inner) = self.inner.write() else {
+            return;
+        };
+
+        inner.names = contacts
+            .iter()
+            .map(|contact| (contact.number.clone(), contact.friendly_name.clone()))
+            .collect();
+        inner.recency = contacts.iter().map(|c| c.number.clone()).collect();
+        inner.fetched_at = Some(Instant::now());
+    }
+
+    /// Move a number to the most recent position, adding it if unknown.
+    pub fn touch(&self, number: &str) {
+        let Ok(mut inner) = self.inner.write() else {
+            return;
+        };
+
+        inner.recency.retain(|existing| existing != number);
+        inner.recency.insert(0, number.to_string());
+        inner.names.entry(number.to_string()).or_insert(None);
+    }
+
+    /// Is the number in the cache, or None if contacts have never been fetched.
+    pub fn is_known(&self, number: &str) -> Option<bool> {
+        let inner = self.inner.read().ok()?;
+        inner.fetched_at?;
+        Some(inner.names.contains_key(number))
+    }
+
+    /// Get the cached friendly name for a number, if it has one.
+    pub fn friendly_name(&self, number: &str) -> Option<String> {
+        self.inner.read().ok()?.names.get(number).cloned().flatten()
+    }
+
+    pub fn set_friendly_name(&self, number: &str, friendly_name: Option<String>) {
+        if let Ok(mut inner) = self.inner.write() {
+            inner.names.insert(number.to_string(), friendly_name);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use ratatui::style::palette::tailwind;
+use ratatui::style::Color;
+use sms_client::types::http::{
+    HttpModemBatteryLevelResponse, HttpModemSignalStrengthResponse, HttpSmsDeviceInfoResponse,
+};
+
+/// How many bars a full signal is drawn with.
+pub const SIGNAL_BARS: usize = 5;
+
+/// Signal strength as a percentage, from the RSSI (0-31).
+/// RSSI 0 = -113 dBm (worst), RSSI 31 = -51 dBm (best), 99 = unknown (0%).
+pub fn signal_percentage(signal: HttpModemSignalStrengthResponse) -> u8 {
+    if signal.rssi == 99 {
+        0 // Unknown signal
+    } else if signal.rssi > 31 {
+        100 // Cap at 100% for invalid values
+    } else {
+        (signal.rssi as f32 / 31.0 * 100.0)
+            .clamp(0.0, 255.0)
+            .round() as u8
+    }
+}
+
+pub fn signal_quality(signal: HttpModemSignalStrengthResponse) -> (&'static str, Color) {
+    if signal.rssi == 99 {
+        return ("Unknown", Color::Gray);
+    }
+
+    match signal_percentage(signal) {
+        90..=100 => ("Excellent", Color::Green),
+        70..=89 => ("Good", tailwind::LIME.c400),
+        50..=69 => ("Fair", Color::Yellow),
+        25..=49 => ("Poor", tailwind::ORANGE.c400),
+        _ => ("Very Poor", Color::Red),
+    }
+}
+
+/// How many of the signal bars are filled, 0 when unknown.
+pub fn signal_bars(signal: HttpModemSignalStrengthResponse) -> usize {
+    if signal.rssi == 99 || signal.rssi == 0 {
+        0
+    } else {
+        ((signal.rssi.min(31) as f32 / 31.0) * SIGNAL_BARS as f32).ceil() as usize
+    }
+}
+
+/// Battery charge as a percentage, capped at 100.
+pub fn battery_percentage(battery: HttpModemBatteryLevelResponse) -> u8 {
+    f32::from(battery.charge.min(100)) as u8
+}
+
+pub fn battery_color(percentage: u8) -> Color {
+    match percentage {
+        81..=100 => Color::Green,
+        61..=80 => tailwind::LIME.c400,
+        41..=60 => Color::Yellow,
+        21..=40 => tailwind::ORANGE.c400,
+        _ => Color::Red,
+    }
+}
+
+pub fn battery_status_text(battery: HttpModemBatteryLevelResponse) -> &'static str {
+    match battery.status {
+        0 => "Not Charging",
+        1 => "Charging",
+        2 => "No Battery",
+        _ => "Unknown",
+    }
+}
+
+/// A summary of the modem's signal and battery for the status bar, fetched by the
+/// `App` periodically and whenever the device info view loads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceStatus {
+    pub signal: Option<(usize, Color)>, // Filled bars and quality color, None if unknown
+    pub battery: Option<(u8, bool)>,    // Percentage and charging, None if unknown
+}
+impl From<&HttpSmsDeviceInfoResponse> for DeviceStatus {
+    fn from(info: &HttpSmsDeviceInfoResponse) -> Self {
+        let signal = info
+            .signal
+            .filter(|signal| signal.rssi != 99)
+            .map(|signal| (signal_bars(signal), signal_quality(signal).1));
+        let battery = info
+            .battery
+            .filter(|battery| battery.status != 2) // No battery
+            .map(|battery| (battery_percentage(battery), battery.status == 1));
+
+        Self { signal, battery }
+    }
+}
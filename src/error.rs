@@ -25,4 +25,82 @@ impl From<sms_client::error::ClientError> for AppError {
     }
 }
 
+impl AppError {
+    /// Did the server reject the auth token.
+    pub fn is_unauthorized(&self) -> bool {
+        match self {
+            AppError::Http(_) | AppError::Sms(_) => HttpStatus::Unauthorized.matches(self),
+            AppError::Config(_) => false,
+        }
+    }
+}
+
+/// A response status the app reacts to. The client doesn't expose the status as its own
+/// variant, only in the formatted error as a status line like "401 Unauthorized", so this
+/// matches the whole line rather than any "401" in a phone number or message id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpStatus {
+    Unauthorized,
+    TooManyRequests,
+}
+impl HttpStatus {
+    fn status_line(self) -> &'static str {
+        match self {
+            HttpStatus::Unauthorized => "401 Unauthorized",
+            HttpStatus::TooManyRequests => "429 Too Many Requests",
+        }
+    }
+
+    /// Was the request that failed with `error` rejected with this status.
+    pub fn matches(self, error: &impl std::fmt::Display) -> bool {
+        let message = error.to_string();
+        message
+            .match_indices(self.status_line())
+            .any(|(i, _)| !message[..i].ends_with(|c: char| c.is_ascii_alphanumeric()))
+    }
+}
+
 pub type AppResult<T> = Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_status_line() {
+        let cases = [
+            (
+                "HTTP status client error (401 Unauthorized) for url (http://localhost/)",
+                true,
+            ),
+            ("401 Unauthorized", true),
+            ("Request failed: 401 Unauthorized: invalid token", true),
+            ("Failed to send to +441401555123", false),
+            ("Message #401 wasn't found", false),
+            ("Message 401 wasn't found (404 Not Found)", false),
+            ("1401 Unauthorized", false),
+            ("Unauthorized", false),
+            ("429 Too Many Requests", false),
+        ];
+        for (message, expected) in cases {
+            assert_eq!(
+                HttpStatus::Unauthorized.matches(&message),
+                expected,
+                "matching {message:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_rate_limit() {
+        let message = "HTTP status client error (429 Too Many Requests)";
+        assert!(HttpStatus::TooManyRequests.matches(&message));
+        assert!(!HttpStatus::TooManyRequests.matches(&"Sent to +44429555123"));
+        assert!(!HttpStatus::TooManyRequests.matches(&"401 Unauthorized"));
+    }
+
+    #[test]
+    fn config_errors_are_never_unauthorized() {
+        assert!(!AppError::Config("401 Unauthorized".to_string()).is_unauthorized());
+    }
+}
@@ -0,0 +1,257 @@
+use std::sync::OnceLock;
+
+use crate::types::SpinnerStyle;
+
+/// The emoji and symbols drawn by the UI, so they can be swapped for ASCII on terminals
+/// or fonts that show them as boxes or misalign them. Prefixes (eg: `conversation`)
+/// include their trailing space, so an empty ASCII prefix leaves no gap behind.
+#[derive(Debug, Clone)]
+pub struct Glyphs {
+    pub separator: &'static str, // Between title parts, eg: "Viewing Messages ｜ Name"
+    pub bullet: &'static str,
+    pub arrow_left: &'static str,
+    pub arrow_right: &'static str,
+    pub arrow_up: &'static str,
+    pub arrow_down: &'static str,
+    pub newline: &'static str,
+    pub block: &'static str,
+    pub cursor: &'static str,
+    pub selection: &'static str, // Marks the selected table row
+    pub spinner: &'static [char],
+
+    // Key names in help text.
+    pub keys_up_down: &'static str,
+    pub keys_left_right: &'static str,
+    pub shift: &'static str,
+
+    // Box drawing, for outlines that aren't widget borders.
+    pub horizontal: &'static str,
+    pub vertical: &'static str,
+    pub top_left: &'static str,
+    pub top_right: &'static str,
+    pub bottom_left: &'static str,
+    pub bottom_right: &'static str,
+    pub rounded_bottom_left: &'static str,
+    pub rounded_bottom_right: &'static str,
+
+    // Notification icons.
+    pub new_message: &'static str,
+    pub status_online: &'static str,
+    pub status_offline: &'static str,
+    pub status_changing: &'static str,
+    pub ws_connected: &'static str,
+    pub ws_reconnecting: &'static str,
+    pub ws_disconnected: &'static str,
+    pub failure: &'static str,
+    pub success: &'static str,
+    pub copied: &'static str,
+    pub pinned: &'static str,
+
+    // Footer and title prefixes.
+    pub conversation: &'static str,
+    pub message_count: &'static str,
+    pub timezone: &'static str,
+    pub search: &'static str,
+    pub loading: &'static str,
+    pub all_loaded: &'static str, // Suffix
+    pub device: &'static str,
+    pub phone: &'static str,
+    pub battery: &'static str,
+    pub signal: &'static str,
+    pub charging: &'static str,
+
+    // Delivery reports.
+    pub report_sent: &'static str,
+    pub report_received: &'static str,
+    pub report_retrying: &'static str,
+    pub report_failed: &'static str,
+    pub delivered: &'static str,
+    pub undelivered: &'static str,
+    pub pending: &'static str,
+
+    // Message labels.
+    pub label_important: &'static str,
+    pub label_follow_up: &'static str,
+    pub label_done: &'static str,
+}
+
+static UNICODE: Glyphs = Glyphs {
+    separator: "｜",
+    bullet: "•",
+    arrow_left: "←",
+    arrow_right: "→",
+    arrow_up: "↑",
+    arrow_down: "↓",
+    newline: "⏎",
+    block: "█",
+    cursor: "█",
+    selection: "█",
+    spinner: SpinnerStyle::Braille.frames(),
+
+    keys_up_down: "↑/↓",
+    keys_left_right: "←/→",
+    shift: "⇧",
+
+    horizontal: "─",
+    vertical: "│",
+    top_left: "┌",
+    top_right: "┐",
+    bottom_left: "└",
+    bottom_right: "┘",
+    rounded_bottom_left: "╰",
+    rounded_bottom_right: "╯",
+
+    new_message: "📨",
+    status_online: "🟢",
+    status_offline: "🔴",
+    status_changing: "🟡",
+    ws_connected: "🔗",
+    ws_reconnecting: "🔄",
+    ws_disconnected: "⚠️",
+    failure: "❌",
+    success: "✔️",
+    copied: "📋",
+    pinned: "📌",
+
+    conversation: "💬 ",
+    message_count: "✉️ ",
+    timezone: "🕒 ",
+    search: "🔍 ",
+    loading: "⟳ ",
+    all_loaded: " ✓",
+    device: "📱 ",
+    phone: "📞 ",
+    battery: "🔋 ",
+    signal: "📶 ",
+    charging: "⚡ ",
+
+    report_sent: "📤",
+    report_received: "✅",
+    report_retrying: "🔄",
+    report_failed: "❌",
+    delivered: "✓",
+    undelivered: "✗",
+    pending: "◷",
+
+    label_important: "❗",
+    label_follow_up: "🔁",
+    label_done: "✅",
+};
+
+static ASCII: Glyphs = Glyphs {
+    separator: "|",
+    bullet: "*",
+    arrow_left: "<-",
+    arrow_right: "->",
+    arrow_up: "^",
+    arrow_down: "v",
+    newline: "\\n",
+    block: "#",
+    cursor: "_",
+    selection: ">",
+    spinner: SpinnerStyle::Line.frames(),
+
+    keys_up_down: "Up/Down",
+    keys_left_right: "Left/Right",
+    shift: "Shift+",
+
+    horizontal: "-",
+    vertical: "|",
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    rounded_bottom_left: "`",
+    rounded_bottom_right: "'",
+
+    new_message: ">",
+    status_online: "+",
+    status_offline: "x",
+    status_changing: "~",
+    ws_connected: "+",
+    ws_reconnecting: "~",
+    ws_disconnected: "!",
+    failure: "x",
+    success: "+",
+    copied: "+",
+    pinned: "[pinned]",
+
+    conversation: "",
+    message_count: "",
+    timezone: "",
+    search: "Search: ",
+    loading: "",
+    all_loaded: "",
+    device: "",
+    phone: "",
+    battery: "",
+    signal: "",
+    charging: "+ ",
+
+    report_sent: ">",
+    report_received: "+",
+    report_retrying: "~",
+    report_failed: "x",
+    delivered: "+",
+    undelivered: "x",
+    pending: ".",
+
+    label_important: "!",
+    label_follow_up: "~",
+    label_done: "+",
+};
+
+/// Icons for the delivery report statuses, overriding those of the chosen glyph set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportGlyphs {
+    pub sent: String,
+    pub received: String,
+    pub retrying: String,
+    pub failed: String,
+}
+impl ReportGlyphs {
+    /// Parse four comma separated icons in the order sent, received, retrying, failed.
+    pub fn parse(value: &str) -> Option<Self> {
+        let icons: Vec<&str> = value.split(',').map(str::trim).collect();
+        match icons[..] {
+            [sent, received, retrying, failed] if icons.iter().all(|icon| !icon.is_empty()) => {
+                Some(Self {
+                    sent: sent.to_string(),
+                    received: received.to_string(),
+                    retrying: retrying.to_string(),
+                    failed: failed.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+static GLYPHS: OnceLock<&'static Glyphs> = OnceLock::new();
+
+/// Choose the glyph set once at startup, before anything is drawn.
+pub fn init_glyphs(ascii: bool, report: Option<ReportGlyphs>, spinner: Option<SpinnerStyle>) {
+    let base = if ascii { &ASCII } else { &UNICODE };
+    if report.is_none() && spinner.is_none() {
+        let _ = GLYPHS.set(base);
+        return;
+    }
+
+    // Only built once, so leaking the custom icons to keep them 'static is fine.
+    let leak = |icon: String| -> &'static str { Box::leak(icon.into_boxed_str()) };
+    let mut glyphs = base.clone();
+    if let Some(report) = report {
+        glyphs.report_sent = leak(report.sent);
+        glyphs.report_received = leak(report.received);
+        glyphs.report_retrying = leak(report.retrying);
+        glyphs.report_failed = leak(report.failed);
+    }
+    if let Some(spinner) = spinner {
+        glyphs.spinner = spinner.frames();
+    }
+    let _ = GLYPHS.set(Box::leak(Box::new(glyphs)));
+}
+
+pub fn glyphs() -> &'static Glyphs {
+    GLYPHS.get().copied().unwrap_or(&UNICODE)
+}
@@ -0,0 +1,428 @@
+use color_eyre::eyre::anyhow;
+use color_eyre::Result;
+use sms_client::types::http::HttpPaginationOptions;
+use sms_client::types::sms::SmsOutgoingMessage;
+use sms_client::ws::events::{WebsocketEvent, WebsocketReconnectionKind};
+use sms_client::Client;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use crate::connection::{format_version, ServerVersionCheck, MIN_SERVER_VERSION};
+use crate::error::AppError;
+use crate::sanitize::sanitize_text;
+use crate::send_queue::SendQueue;
+use crate::timestamps::DisplayTimezone;
+use crate::types::WebSocketMode;
+use crate::{AppArguments, TerminalConfig};
+
+const WEBSOCKET_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Commands that run to completion without starting the TUI.
+#[derive(Debug, Clone)]
+pub enum HeadlessCommand {
+    Send {
+        phone_number: String,
+        message: String, // "-" reads from stdin
+    },
+    Broadcast {
+        recipients: Vec<String>,
+        recipients_file: Option<PathBuf>, // One number per line
+        message: String,                  // "-" reads from stdin
+        delay: Option<Duration>,          // Between sends, instead of send_interval_ms
+    },
+    Read {
+        phone_number: String,
+        limit: u64,
+        json: bool,
+    },
+    Doctor,
+    PrintConfig {
+        arguments: Box<AppArguments>,
+        client_error: Option<String>, // Reported after printing, rather than instead of it
+    },
+}
+
+/// Run a headless command, returning false if it failed and
+/// the process should exit with a nonzero code.
+pub async fn run(command: HeadlessCommand, config: TerminalConfig) -> Result<bool> {
+    for warning in &config.certificate_warnings {
+        eprintln!("Warning: {warning}");
+    }
+
+    match command {
+        HeadlessCommand::Send {
+            phone_number,
+            message,
+        } => send(config, phone_number, message).await,
+        HeadlessCommand::Broadcast {
+            recipients,
+            recipients_file,
+            message,
+            delay,
+        } => broadcast(config, recipients, recipients_file, message, delay).await,
+        HeadlessCommand::Read {
+            phone_number,
+            limit,
+            json,
+        } => read(config, phone_number, limit, json).await,
+        HeadlessCommand::Doctor => doctor(config).await,
+        HeadlessCommand::PrintConfig {
+            arguments,
+            client_error,
+        } => Ok(print_config(&arguments, client_error.as_deref())),
+    }
+}
+
+/// Print the merged config. A client config error, such as an unreadable certificate,
+/// is reported after everything else has been printed.
+fn print_config(arguments: &AppArguments, client_error: Option<&str>) -> bool {
+    fn show<T: Display>(value: Option<&T>) -> String {
+        value.map_or_else(|| "(unset)".to_string(), ToString::to_string)
+    }
+    fn show_debug<T: std::fmt::Debug>(value: Option<&T>) -> String {
+        show(
+            value
+                .map(|value| format!("{value:?}").to_lowercase())
+                .as_ref(),
+        )
+    }
+    fn redact<T>(value: Option<&T>) -> String {
+        show(value.map(|_| &"<redacted>"))
+    }
+
+    let config_path = AppArguments::config_path();
+    println!(
+        "Config file: {}{}",
+        config_path.display(),
+        if config_path.exists() {
+            ""
+        } else {
+            " (not found)"
+        }
+    );
+    println!();
+
+    let log_file = arguments.log_file.as_ref().map(|path| path.display());
+    let ssl_certificate = arguments
+        .ssl_certificate
+        .as_ref()
+        .map(|path| path.display());
+
+    let entries = vec![
+        ("theme", show_debug(arguments.theme.as_ref())),
+        ("action_primary", show(arguments.action_primary.as_ref())),
+        ("action_danger", show(arguments.action_danger.as_ref())),
+        ("host", show(arguments.host.as_ref())),
+        ("http_uri", show(arguments.http_uri.as_ref())),
+        ("ws_uri", show(arguments.ws_uri.as_ref())),
+        ("ws_enabled", show(arguments.ws_enabled.as_ref())),
+        ("ws_mode", show_debug(arguments.ws_mode.as_ref())),
+        ("ws_max_retries", show(arguments.ws_max_retries.as_ref())),
+        ("auth", redact(arguments.auth.as_ref())),
+        ("load_timeout", show(arguments.load_timeout.as_ref())),
+        (
+            "shutdown_timeout",
+            show(arguments.shutdown_timeout.as_ref()),
+        ),
+        ("key_debounce_ms", show(arguments.key_debounce_ms.as_ref())),
+        (
+            "max_loaded_messages",
+            show(arguments.max_loaded_messages.as_ref()),
+        ),
+        (
+            "phonebook_max_contacts",
+            show(arguments.phonebook_max_contacts.as_ref()),
+        ),
+        ("view_cache_size", show(arguments.view_cache_size.as_ref())),
+        ("send_timeout", show(arguments.send_timeout.as_ref())),
+        (
+            "send_interval_ms",
+            show(arguments.send_interval_ms.as_ref()),
+        ),
+        ("no_resize", show(arguments.no_resize.as_ref())),
+        ("no_mouse", show(arguments.no_mouse.as_ref())),
+        (
+            "content_newlines",
+            show_debug(arguments.content_newlines.as_ref()),
+        ),
+        (
+            "direction_colors",
+            show(arguments.direction_colors.as_ref()),
+        ),
+        (
+            "no_signal_details",
+            show(arguments.no_signal_details.as_ref()),
+        ),
+        ("ascii", show(arguments.ascii.as_ref())),
+        ("report_glyphs", show(arguments.report_glyphs.as_ref())),
+        ("spinner", show_debug(arguments.spinner.as_ref())),
+        ("quiet", show(arguments.quiet.as_ref())),
+        ("open_links", show(arguments.open_links.as_ref())),
+        ("wrap_navigation", show(arguments.wrap_navigation.as_ref())),
+        ("enter_sends", show(arguments.enter_sends.as_ref())),
+        ("vim_keys", show(arguments.vim_keys.as_ref())),
+        (
+            "conversation_refresh_seconds",
+            show(arguments.conversation_refresh_seconds.as_ref()),
+        ),
+        ("debug", show(arguments.debug.as_ref())),
+        ("log_file", show(log_file.as_ref())),
+        ("verbose", show(arguments.verbose.as_ref())),
+        ("timezone", show(arguments.timezone.as_ref())),
+        ("date_format", show(arguments.date_format.as_ref())),
+        ("time_format", show(arguments.time_format.as_ref())),
+        ("ssl_certificate", show(ssl_certificate.as_ref())),
+    ];
+
+    #[cfg(feature = "sentry")]
+    let entries = {
+        let mut entries = entries;
+        entries.push(("sentry", redact(arguments.sentry.as_ref())));
+        entries
+    };
+
+    let width = entries.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    for (key, value) in entries {
+        println!("{key:<width$} = {value}");
+    }
+
+    if let Some(error) = client_error {
+        eprintln!();
+        eprintln!("Config error: {error}");
+        return false;
+    }
+    true
+}
+
+/// The message body to send, reading it from stdin for "-". Returns None if it's empty.
+fn read_body(message: String) -> Result<Option<String>> {
+    let body = if message == "-" {
+        let mut body = String::new();
+        std::io::stdin().read_to_string(&mut body)?;
+        body.trim_end_matches(['\r', '\n']).to_string()
+    } else {
+        message
+    };
+    if body.trim().is_empty() {
+        eprintln!("Refusing to send an empty message");
+        return Ok(None);
+    }
+    Ok(Some(body))
+}
+
+fn outgoing_message(
+    send_timeout: Option<u32>,
+    phone_number: String,
+    body: String,
+) -> SmsOutgoingMessage {
+    let message = SmsOutgoingMessage::simple_message(phone_number, body);
+    match send_timeout {
+        Some(timeout) => message.with_timeout(timeout),
+        None => message,
+    }
+}
+
+async fn send(config: TerminalConfig, phone_number: String, message: String) -> Result<bool> {
+    let Some(body) = read_body(message)? else {
+        return Ok(false);
+    };
+
+    let message = outgoing_message(config.options.send_timeout, phone_number, body);
+    let queue = SendQueue::new(config.options.send_interval);
+    let client = Client::new(config.client).map_err(|e| AppError::Config(e.to_string()))?;
+    let http = client.http_arc().map_err(|e| anyhow!("{e:?}"))?;
+
+    match queue.send(|| http.send_sms(&message)).await {
+        Ok(response) => {
+            println!(
+                "Message #{} was sent (ref {})",
+                response.message_id, response.reference_id
+            );
+            Ok(true)
+        }
+        Err(e) => {
+            eprintln!("Failed to send message: {e}");
+            Ok(false)
+        }
+    }
+}
+
+/// Send the same message to each recipient in turn, reporting each result.
+/// Returns false if any send failed.
+async fn broadcast(
+    config: TerminalConfig,
+    mut recipients: Vec<String>,
+    recipients_file: Option<PathBuf>,
+    message: String,
+    delay: Option<Duration>,
+) -> Result<bool> {
+    if let Some(path) = recipients_file {
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Failed to read recipients from {}: {e}", path.display());
+                return Ok(false);
+            }
+        };
+        recipients.extend(contents.lines().map(str::to_string));
+    }
+
+    // Skip blank lines, comments and repeats, keeping the given order.
+    let mut seen = HashSet::new();
+    recipients.retain(|number| {
+        let number = number.trim();
+        !number.is_empty() && !number.starts_with('#') && seen.insert(number.to_string())
+    });
+    if recipients.is_empty() {
+        eprintln!("No recipients given, use --to or --to-file");
+        return Ok(false);
+    }
+
+    let Some(body) = read_body(message)? else {
+        return Ok(false);
+    };
+
+    let send_timeout = config.options.send_timeout;
+    let queue = SendQueue::new(delay.unwrap_or(config.options.send_interval));
+    let client = Client::new(config.client).map_err(|e| AppError::Config(e.to_string()))?;
+    let http = client.http_arc().map_err(|e| anyhow!("{e:?}"))?;
+
+    let mut failures = 0;
+    for phone_number in &recipients {
+        let phone_number = phone_number.trim().to_string();
+        let message = outgoing_message(send_timeout, phone_number.clone(), body.clone());
+        match queue.send(|| http.send_sms(&message)).await {
+            Ok(response) => println!(
+                "{phone_number}: message #{} was sent (ref {})",
+                response.message_id, response.reference_id
+            ),
+            Err(e) => {
+                eprintln!("{phone_number}: failed to send message: {e}");
+                failures += 1;
+            }
+        }
+    }
+
+    println!(
+        "Sent to {} of {} recipients",
+        recipients.len() - failures,
+        recipients.len()
+    );
+    Ok(failures == 0)
+}
+
+async fn read(
+    config: TerminalConfig,
+    phone_number: String,
+    limit: u64,
+    json: bool,
+) -> Result<bool> {
+    let client = Client::new(config.client).map_err(|e| AppError::Config(e.to_string()))?;
+    let http = client.http_arc().map_err(|e| anyhow!("{e:?}"))?;
+
+    let pagination = HttpPaginationOptions::default().with_limit(limit);
+    let messages = match http.get_messages(&phone_number, Some(pagination)).await {
+        Ok(messages) => messages,
+        Err(e) => {
+            eprintln!("Failed to read messages: {e}");
+            return Ok(false);
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&messages)?);
+        return Ok(true);
+    }
+    for message in &messages {
+        let timestamp = message
+            .completed_at
+            .or(message.created_at)
+            .and_then(DisplayTimezone::from_unix)
+            .map_or_else(String::new, |dt| config.options.timestamps.date_time(dt));
+        let direction = if message.is_outgoing { "OUT" } else { "IN " };
+        let id = message
+            .message_id
+            .map_or_else(|| "?".to_string(), |id| id.to_string());
+
+        let content = sanitize_text(&message.message_content, config.options.content_newlines);
+        println!("#{id:<6} {direction} {timestamp}  {content}");
+    }
+    Ok(true)
+}
+
+fn print_check(name: &str, started: Instant, result: &Result<String, impl Display>) {
+    let elapsed = started.elapsed().as_millis();
+    match result {
+        Ok(detail) => println!("[PASS] {name:<10} ({elapsed}ms) {detail}"),
+        Err(e) => println!("[FAIL] {name:<10} ({elapsed}ms) {e}"),
+    }
+}
+
+async fn doctor(config: TerminalConfig) -> Result<bool> {
+    let client = Client::new(config.client).map_err(|e| AppError::Config(e.to_string()))?;
+    let http = client.http_arc().map_err(|e| anyhow!("{e:?}"))?;
+
+    // HTTP, which also validates the auth token.
+    let started = Instant::now();
+    let result = http.get_device_info().await;
+    let version = result.as_ref().ok().map(|info| info.version.clone());
+    let result = result.map(|info| format!("server version {}", info.version));
+    print_check("HTTP", started, &result);
+    let mut passed = result.is_ok();
+
+    if let Some(version) = version {
+        let minimum = format_version(MIN_SERVER_VERSION);
+        match ServerVersionCheck::check(&version) {
+            ServerVersionCheck::Supported => println!("[PASS] Version    {version} (>= {minimum})"),
+            ServerVersionCheck::Unsupported => {
+                println!("[FAIL] Version    {version} (requires >= {minimum})");
+                passed = false;
+            }
+            ServerVersionCheck::Unknown => {
+                println!("[WARN] Version    unrecognised '{version}' (requires >= {minimum})");
+            }
+        }
+    }
+
+    if config.websocket != WebSocketMode::Off {
+        let started = Instant::now();
+        let result = websocket_handshake(&client).await;
+        print_check("WebSocket", started, &result);
+        passed &= result.is_ok();
+    } else {
+        println!("[SKIP] WebSocket  (disabled)");
+    }
+
+    Ok(passed)
+}
+
+/// Start the WebSocket and wait for it to report a connection, or fail.
+async fn websocket_handshake(client: &Client) -> Result<String, String> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    client
+        .on_message_simple(move |message| {
+            if let WebsocketEvent::Reconnection(WebsocketReconnectionKind::Connected) = message {
+                let _ = tx.send(());
+            }
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let worker = client.clone();
+    let blocking = tokio::spawn(async move { worker.start_blocking_websocket().await });
+    tokio::select! {
+        _ = rx.recv() => Ok("connection established".to_string()),
+        result = blocking => Err(match result {
+            Ok(Ok(())) => "connection was closed by the server".to_string(),
+            Ok(Err(e)) => e.to_string(),
+            Err(e) => e.to_string(),
+        }),
+        () = tokio::time::sleep(WEBSOCKET_CONNECT_TIMEOUT) => {
+            Err("timed out waiting for connection".to_string())
+        }
+    }
+}
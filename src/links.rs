@@ -0,0 +1,50 @@
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Find the http(s) links in some text, in the order they appear.
+pub fn find_urls(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .filter_map(|word| {
+            let start = word.find("https://").or_else(|| word.find("http://"))?;
+
+            // Drop punctuation commonly written around links in prose.
+            let url = word[start..]
+                .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']', '>', '\'', '"']);
+            (url.len() > "https://".len()).then(|| url.to_string())
+        })
+        .collect()
+}
+
+/// Open a link in the system browser, without waiting for it to exit.
+///
+/// The link comes from a message, so it's passed straight to the opener as a single
+/// argument and never through a shell, where characters like `&` would be interpreted.
+pub fn open_url(url: &str) -> io::Result<()> {
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Not an http(s) link",
+        ));
+    }
+
+    #[cfg(target_os = "windows")]
+    let mut command = Command::new("explorer");
+    #[cfg(target_os = "macos")]
+    let mut command = Command::new("open");
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = Command::new("xdg-open");
+
+    // Anything printed would draw over the TUI.
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Reap the opener once it exits (usually straight away) so it isn't left a zombie.
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
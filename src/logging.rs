@@ -0,0 +1,26 @@
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::Level;
+
+use crate::error::{AppError, AppResult};
+
+/// Write logs to a file, since the TUI owns the terminal. Logs from sms-client and
+/// its HTTP and WebSocket stack are captured too, down to debug level if `verbose`.
+pub fn init_logging(path: &Path, verbose: bool) -> AppResult<()> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| {
+            AppError::Config(format!("Failed to open log file {}: {e}", path.display()))
+        })?;
+
+    let level = if verbose { Level::DEBUG } else { Level::INFO };
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_max_level(level)
+        .try_init()
+        .map_err(|e| AppError::Config(format!("Failed to start logging: {e}")))
+}
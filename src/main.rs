@@ -2,23 +2,56 @@
 
 use clap::{Parser, Subcommand};
 use color_eyre::Result;
+use ratatui::style::Color;
 use sms_client::config::{ClientConfig, TLSConfig, WebSocketConfig};
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
 
 mod app;
+mod certificate;
+mod clipboard;
+mod connection;
+mod contacts;
+mod device_status;
 mod error;
+mod glyphs;
+mod headless;
+mod links;
+mod logging;
 mod modals;
+mod sanitize;
+mod send_queue;
+mod state;
 mod theme;
+mod timestamps;
 mod types;
 mod ui;
 
+use crate::contacts::CONTACT_CACHE_LIMIT;
 use crate::error::{AppError, AppResult};
-use crate::theme::PresetTheme;
+use crate::glyphs::ReportGlyphs;
+use crate::headless::HeadlessCommand;
+use crate::theme::{ActionColors, PresetTheme};
+use crate::timestamps::TimestampFormatter;
+use crate::types::{ContentNewlines, SpinnerStyle, WebSocketMode};
 use crate::ui::views::ViewStateRequest;
 use app::App;
 use serde::{Deserialize, Serialize};
 
 const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
+const DEFAULT_WS_MAX_RETRIES: u32 = 5;
+const DEFAULT_LOAD_TIMEOUT: u64 = 20;
+const DEFAULT_KEY_DEBOUNCE_MS: u64 = 50;
+const MAX_KEY_DEBOUNCE_MS: u64 = 500;
+const DEFAULT_MAX_LOADED_MESSAGES: usize = 1000;
+const MIN_MAX_LOADED_MESSAGES: usize = 100;
+const MAX_SEND_TIMEOUT: u32 = 600;
+const DEFAULT_SEND_INTERVAL_MS: u64 = 1000;
+const DEFAULT_SHUTDOWN_TIMEOUT: u64 = 5;
+const DEFAULT_VIEW_CACHE_SIZE: usize = 3;
+const MAX_VIEW_CACHE_SIZE: usize = 10;
+const MAX_SHUTDOWN_TIMEOUT: u64 = 60;
 const FEATURE_VERSION: &str = if cfg!(feature = "sentry") {
     concat!(env!("CARGO_PKG_VERSION"), "+sentry")
 } else {
@@ -58,6 +91,9 @@ enum Commands {
         #[arg(help = "Phone number to compose a message for")]
         phone_number: String,
 
+        #[arg(long, help = "Text to pre-fill the message body with")]
+        body: Option<String>,
+
         #[command(flatten)]
         args: AppArguments,
     },
@@ -67,14 +103,94 @@ enum Commands {
         #[command(flatten)]
         args: AppArguments,
     },
+
+    #[command(about = "Send a single SMS without starting the TUI, then exit")]
+    Send {
+        #[arg(help = "Phone number to send the message to")]
+        phone_number: String,
+
+        #[arg(help = "Message body, or - to read it from stdin")]
+        message: String,
+
+        #[command(flatten)]
+        args: AppArguments,
+    },
+
+    #[command(about = "Send one SMS to several numbers without starting the TUI, then exit")]
+    Broadcast {
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Comma separated phone numbers to send to"
+        )]
+        to: Vec<String>,
+
+        #[arg(long, help = "File of phone numbers to send to, one per line")]
+        to_file: Option<PathBuf>,
+
+        #[arg(
+            long,
+            help = "Milliseconds to wait between sends (default send_interval_ms)"
+        )]
+        delay_ms: Option<u64>,
+
+        #[arg(help = "Message body, or - to read it from stdin")]
+        message: String,
+
+        #[command(flatten)]
+        args: AppArguments,
+    },
+
+    #[command(
+        about = "Print the latest messages for a number without starting the TUI, then exit"
+    )]
+    Read {
+        #[arg(help = "Phone number to read messages for")]
+        phone_number: String,
+
+        #[arg(long, default_value_t = 20, help = "Number of messages to fetch")]
+        limit: u64,
+
+        #[arg(long, action = clap::ArgAction::SetTrue, help = "Print the raw messages as JSON")]
+        json: bool,
+
+        #[command(flatten)]
+        args: AppArguments,
+    },
+
+    #[command(about = "Check the server is reachable and authentication is valid, then exit")]
+    Doctor {
+        #[command(flatten)]
+        args: AppArguments,
+    },
+
+    #[command(about = "Print the effective configuration after merging CLI and config file")]
+    Config {
+        #[command(flatten)]
+        args: AppArguments,
+    },
 }
 
 #[derive(Parser, Serialize, Deserialize, Debug, Clone)]
-struct AppArguments {
+pub struct AppArguments {
     #[arg(long, value_enum, help = "Select a built-in theme to start with")]
     #[serde(default)]
     pub theme: Option<PresetTheme>,
 
+    #[arg(
+        long,
+        help = "Color of primary buttons like Yes and OK (a name like green, or #rrggbb)"
+    )]
+    #[serde(default)]
+    pub action_primary: Option<String>,
+
+    #[arg(
+        long,
+        help = "Color of cancelling buttons like No and Cancel (a name like red, or #rrggbb)"
+    )]
+    #[serde(default)]
+    pub action_danger: Option<String>,
+
     #[arg(
         long,
         help = "Set the server host for HTTP and WebSocket (e.g localhost:3000)"
@@ -96,10 +212,25 @@ struct AppArguments {
     #[serde(default)]
     pub ws_uri: Option<String>,
 
-    #[arg(long, help = "Enable WebSocket support")]
+    #[arg(long, help = "Enable WebSocket support, overriding ws-mode")]
     #[serde(default)]
     pub ws_enabled: Option<bool>,
 
+    #[arg(
+        long,
+        value_enum,
+        help = "Whether to connect the WebSocket: on, off or auto (default, falls back if it can't connect)"
+    )]
+    #[serde(default)]
+    pub ws_mode: Option<WebSocketMode>,
+
+    #[arg(
+        long,
+        help = "Maximum consecutive WebSocket reconnection attempts before showing an error"
+    )]
+    #[serde(default)]
+    pub ws_max_retries: Option<u32>,
+
     #[arg(
         long,
         help = "Authorization token to use for HTTP and WebSocket requests"
@@ -107,6 +238,223 @@ struct AppArguments {
     #[serde(default)]
     pub auth: Option<String>,
 
+    #[arg(
+        long,
+        help = "Seconds to wait for a view to load before showing an error (default 20)"
+    )]
+    #[serde(default)]
+    pub load_timeout: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Seconds to wait on exit for queued messages to send, 0-60 (default 5)"
+    )]
+    #[serde(default)]
+    pub shutdown_timeout: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Milliseconds to ignore repeats of the same key for, 0-500 (default 50)"
+    )]
+    #[serde(default)]
+    pub key_debounce_ms: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Messages kept loaded per conversation before the furthest are evicted, 0 for unlimited (default 1000)"
+    )]
+    #[serde(default)]
+    pub max_loaded_messages: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Most recent contacts listed in the phonebook, up to 50 (default fits the window)"
+    )]
+    #[serde(default)]
+    pub phonebook_max_contacts: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Recently left conversations kept loaded to reopen instantly, 0 to disable (default 3)"
+    )]
+    #[serde(default)]
+    pub view_cache_size: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Seconds to wait for a message to send, 1-600 (default 30 per SMS part)"
+    )]
+    #[serde(default)]
+    pub send_timeout: Option<u32>,
+
+    #[arg(
+        long,
+        help = "Minimum milliseconds between sending messages, to avoid rate limits (default 1000)"
+    )]
+    #[serde(default)]
+    pub send_interval_ms: Option<u64>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Don't resize small terminals to 160x50 on startup"
+    )]
+    #[serde(default)]
+    pub no_resize: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Disable mouse support, leaving the terminal's own selection behaviour"
+    )]
+    #[serde(default)]
+    pub no_mouse: Option<bool>,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "How newlines in messages are shown: strip (default), symbol or keep"
+    )]
+    #[serde(default)]
+    pub content_newlines: Option<ContentNewlines>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Tint message rows by direction instead of striping them alternately"
+    )]
+    #[serde(default)]
+    pub direction_colors: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Hide the BER, raw RSSI and dBm lines in device info until toggled with (t)"
+    )]
+    #[serde(default)]
+    pub no_signal_details: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Draw ASCII instead of emoji and symbols, for terminals or fonts that can't show them"
+    )]
+    #[serde(default)]
+    pub ascii: Option<bool>,
+
+    #[arg(
+        long,
+        help = "Delivery report icons as 'sent,received,retrying,failed', eg: 'S,D,R,F'"
+    )]
+    #[serde(default)]
+    pub report_glyphs: Option<String>,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Loading spinner: braille (default), dots, line or arrows"
+    )]
+    #[serde(default)]
+    pub spinner: Option<SpinnerStyle>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Suppress the startup warnings for a disabled WebSocket or unconfigured Sentry"
+    )]
+    #[serde(default)]
+    pub quiet: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Allow opening links from messages in the system browser (o)"
+    )]
+    #[serde(default)]
+    pub open_links: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Wrap around from the last message to the first (and back) when navigating"
+    )]
+    #[serde(default)]
+    pub wrap_navigation: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Send with Enter when composing, using Shift+Enter (or Alt+Enter) for a new line"
+    )]
+    #[serde(default)]
+    pub enter_sends: Option<bool>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Navigate lists with h/j/k/l as well as the arrow keys"
+    )]
+    #[serde(default)]
+    pub vim_keys: Option<bool>,
+
+    #[arg(
+        long,
+        help = "Without a WebSocket, refresh the open conversation after this many idle seconds"
+    )]
+    #[serde(default)]
+    pub conversation_refresh_seconds: Option<u64>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Enable developer tools, such as the raw message inspector"
+    )]
+    #[serde(default)]
+    pub debug: Option<bool>,
+
+    #[arg(
+        long,
+        value_hint = clap::ValueHint::FilePath,
+        help = "Write logs to this file, as the TUI can't show them"
+    )]
+    #[serde(default)]
+    pub log_file: Option<PathBuf>,
+
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        help = "Log debug detail, to log-file or sms-terminal.log beside the config file"
+    )]
+    #[serde(default)]
+    pub verbose: Option<bool>,
+
+    #[arg(
+        long,
+        help = "IANA timezone to display timestamps in (e.g. Europe/London), defaults to local"
+    )]
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    #[arg(long, help = "strftime date format for timestamps (e.g. %m/%d/%y)")]
+    #[serde(default)]
+    pub date_format: Option<String>,
+
+    #[arg(long, help = "strftime time format for timestamps (e.g. %I:%M %p)")]
+    #[serde(default)]
+    pub time_format: Option<String>,
+
     #[serde(default, deserialize_with = "deserialize_certificate_filepath")]
     #[arg(long, value_hint = clap::ValueHint::FilePath, help = "An SSL certificate filepath to use for SMS connections")]
     pub ssl_certificate: Option<PathBuf>,
@@ -120,6 +468,7 @@ impl AppArguments {
         let file_config = Self::load_or_create_file()?;
 
         // CLI always takes priority over config file values.
+        let ws_mode = self.websocket_mode().or(file_config.websocket_mode());
         Ok(Self {
             theme: self
                 .theme
@@ -132,7 +481,43 @@ impl AppArguments {
             http_uri: self.http_uri.or(file_config.http_uri),
             ws_uri: self.ws_uri.or(file_config.ws_uri),
             ws_enabled: self.ws_enabled.or(file_config.ws_enabled),
+            ws_mode,
+            ws_max_retries: self.ws_max_retries.or(file_config.ws_max_retries),
             auth: self.auth.or(file_config.auth),
+            load_timeout: self.load_timeout.or(file_config.load_timeout),
+            shutdown_timeout: self.shutdown_timeout.or(file_config.shutdown_timeout),
+            key_debounce_ms: self.key_debounce_ms.or(file_config.key_debounce_ms),
+            max_loaded_messages: self.max_loaded_messages.or(file_config.max_loaded_messages),
+            phonebook_max_contacts: self
+                .phonebook_max_contacts
+                .or(file_config.phonebook_max_contacts),
+            view_cache_size: self.view_cache_size.or(file_config.view_cache_size),
+            send_timeout: self.send_timeout.or(file_config.send_timeout),
+            send_interval_ms: self.send_interval_ms.or(file_config.send_interval_ms),
+            no_resize: self.no_resize.or(file_config.no_resize),
+            no_mouse: self.no_mouse.or(file_config.no_mouse),
+            content_newlines: self.content_newlines.or(file_config.content_newlines),
+            ascii: self.ascii.or(file_config.ascii),
+            report_glyphs: self.report_glyphs.or(file_config.report_glyphs),
+            action_primary: self.action_primary.or(file_config.action_primary),
+            action_danger: self.action_danger.or(file_config.action_danger),
+            spinner: self.spinner.or(file_config.spinner),
+            direction_colors: self.direction_colors.or(file_config.direction_colors),
+            no_signal_details: self.no_signal_details.or(file_config.no_signal_details),
+            quiet: self.quiet.or(file_config.quiet),
+            open_links: self.open_links.or(file_config.open_links),
+            wrap_navigation: self.wrap_navigation.or(file_config.wrap_navigation),
+            enter_sends: self.enter_sends.or(file_config.enter_sends),
+            vim_keys: self.vim_keys.or(file_config.vim_keys),
+            conversation_refresh_seconds: self
+                .conversation_refresh_seconds
+                .or(file_config.conversation_refresh_seconds),
+            debug: self.debug.or(file_config.debug),
+            log_file: self.log_file.or(file_config.log_file),
+            verbose: self.verbose.or(file_config.verbose),
+            timezone: self.timezone.or(file_config.timezone),
+            date_format: self.date_format.or(file_config.date_format),
+            time_format: self.time_format.or(file_config.time_format),
             ssl_certificate: self.ssl_certificate.or(file_config.ssl_certificate),
 
             #[cfg(feature = "sentry")]
@@ -158,6 +543,15 @@ impl AppArguments {
         Ok(default_config)
     }
 
+    /// The WebSocket mode these arguments ask for, where an explicit `ws_enabled` wins over
+    /// `ws_mode`. Resolved per source while merging, so `--ws-mode` on the command line still
+    /// overrides `ws_enabled` in the config file.
+    fn websocket_mode(&self) -> Option<WebSocketMode> {
+        self.ws_enabled
+            .map(WebSocketMode::from_enabled)
+            .or(self.ws_mode)
+    }
+
     pub fn save(&self) -> AppResult<()> {
         let config_path = Self::config_path();
         if let Some(parent) = config_path.parent() {
@@ -173,7 +567,12 @@ impl AppArguments {
             .map_err(|e| AppError::Config(format!("Failed to write config file: {e}")))
     }
 
-    fn config_path() -> PathBuf {
+    /// Where `verbose` logs to if no log file is given.
+    fn default_log_path() -> PathBuf {
+        Self::config_path().with_file_name("sms-terminal.log")
+    }
+
+    pub fn config_path() -> PathBuf {
         // Check if local config exists first
         let local = PathBuf::from("sms-terminal-config.toml");
         if local.exists() {
@@ -212,8 +611,40 @@ impl Default for AppArguments {
             host: Some("localhost:3000".to_string()),
             http_uri: None,
             ws_uri: None,
-            ws_enabled: Some(false),
+            ws_enabled: None,
+            ws_mode: Some(WebSocketMode::default()),
+            ws_max_retries: Some(DEFAULT_WS_MAX_RETRIES),
             auth: None,
+            load_timeout: Some(DEFAULT_LOAD_TIMEOUT),
+            shutdown_timeout: Some(DEFAULT_SHUTDOWN_TIMEOUT),
+            key_debounce_ms: Some(DEFAULT_KEY_DEBOUNCE_MS),
+            max_loaded_messages: Some(DEFAULT_MAX_LOADED_MESSAGES),
+            phonebook_max_contacts: None,
+            view_cache_size: Some(DEFAULT_VIEW_CACHE_SIZE),
+            send_timeout: None,
+            send_interval_ms: Some(DEFAULT_SEND_INTERVAL_MS),
+            no_resize: Some(false),
+            no_mouse: Some(false),
+            content_newlines: Some(ContentNewlines::default()),
+            direction_colors: Some(false),
+            no_signal_details: Some(false),
+            ascii: Some(false),
+            report_glyphs: None,
+            action_primary: None,
+            action_danger: None,
+            spinner: None,
+            quiet: Some(false),
+            open_links: Some(false),
+            wrap_navigation: Some(false),
+            enter_sends: Some(false),
+            vim_keys: Some(false),
+            conversation_refresh_seconds: None,
+            debug: Some(false),
+            log_file: None,
+            verbose: Some(false),
+            timezone: None,
+            date_format: None,
+            time_format: None,
             ssl_certificate: None,
 
             #[cfg(feature = "sentry")]
@@ -222,13 +653,54 @@ impl Default for AppArguments {
     }
 }
 
+/// Runtime options shared with views and modals through the `AppContext`.
+#[derive(Debug, Clone, Default)]
+pub struct AppOptions {
+    pub debug: bool,
+    pub open_links: bool,
+    pub wrap_navigation: bool,
+    pub enter_sends: bool,
+    pub vim_keys: bool,
+    pub direction_colors: bool,
+    pub signal_details: bool, // Until toggled, which is remembered in the state file
+    pub content_newlines: ContentNewlines,
+    pub max_loaded_messages: Option<usize>, // None when unlimited
+    pub phonebook_max_contacts: Option<usize>, // None to fit the window
+    pub view_cache_size: usize,
+    pub send_timeout: Option<u32>, // None to scale with the message's SMS parts
+    pub send_interval: Duration,
+    pub load_timeout: Duration,
+    pub timestamps: TimestampFormatter,
+}
+
 /// Contained config representation passed into App.
 #[derive(Debug)]
 pub struct TerminalConfig {
     pub client: ClientConfig,
+    pub client_arguments: AppArguments, // To rebuild the client, eg: with a new auth token
     pub theme: PresetTheme,
-    pub websocket: bool,
+    pub action_colors: ActionColors,
+    pub websocket: WebSocketMode,
+    pub websocket_max_retries: u32,
+    pub shutdown_timeout: Duration,
+    pub server_host: String,
+    pub server_uris: (String, Option<String>), // http, ws
+    pub auth_configured: bool,
+    pub key_debounce: Duration,
+    pub resize: bool,
+    pub mouse: bool,
+    pub quiet: bool,
+    pub conversation_refresh: Option<Duration>,
     pub starting_view: Option<ViewStateRequest>,
+    pub headless: Option<HeadlessCommand>,
+    pub options: AppOptions,
+    pub timestamp_errors: Vec<String>,
+    pub certificate_warnings: Vec<String>,
+    pub log_file: Option<PathBuf>,
+    pub verbose: bool,
+    pub ascii: bool,
+    pub report_glyphs: Option<ReportGlyphs>,
+    pub spinner: Option<SpinnerStyle>, // None for the glyph set's own
 
     #[cfg(feature = "sentry")]
     pub sentry: Option<String>,
@@ -236,8 +708,9 @@ pub struct TerminalConfig {
 impl TerminalConfig {
     pub fn parse() -> Result<Self> {
         let cli = Cli::parse();
+        let print_config = matches!(cli.command, Some(Commands::Config { .. }));
 
-        let (starting_view, arguments) = match cli.command {
+        let (starting_view, headless, arguments) = match cli.command {
             Some(Commands::Messages {
                 phone_number,
                 reversed,
@@ -245,54 +718,299 @@ impl TerminalConfig {
             }) => (
                 Some(ViewStateRequest::Messages {
                     phone_number,
-                    reversed,
+                    // Only override the remembered order if the flag was given.
+                    reversed: reversed.then_some(true),
                 }),
+                None,
                 args,
             ),
-            Some(Commands::Compose { phone_number, args }) => {
-                (Some(ViewStateRequest::Compose { phone_number }), args)
-            }
-            Some(Commands::Phonebook { args }) => (Some(ViewStateRequest::Phonebook), args),
-            None => (None, cli.global_args),
+            Some(Commands::Compose {
+                phone_number,
+                body,
+                args,
+            }) => (
+                Some(ViewStateRequest::Compose {
+                    phone_number,
+                    initial_text: body,
+                }),
+                None,
+                args,
+            ),
+            Some(Commands::Phonebook { args }) => (Some(ViewStateRequest::Phonebook), None, args),
+            Some(Commands::Send {
+                phone_number,
+                message,
+                args,
+            }) => (
+                None,
+                Some(HeadlessCommand::Send {
+                    phone_number,
+                    message,
+                }),
+                args,
+            ),
+            Some(Commands::Broadcast {
+                to,
+                to_file,
+                delay_ms,
+                message,
+                args,
+            }) => (
+                None,
+                Some(HeadlessCommand::Broadcast {
+                    recipients: to,
+                    recipients_file: to_file,
+                    message,
+                    delay: delay_ms.map(Duration::from_millis),
+                }),
+                args,
+            ),
+            Some(Commands::Read {
+                phone_number,
+                limit,
+                json,
+                args,
+            }) => (
+                None,
+                Some(HeadlessCommand::Read {
+                    phone_number,
+                    limit,
+                    json,
+                }),
+                args,
+            ),
+            Some(Commands::Doctor { args }) => (None, Some(HeadlessCommand::Doctor), args),
+            Some(Commands::Config { args }) => (None, None, args),
+            None => (None, None, cli.global_args),
         };
 
         let arguments = arguments.load_with_file_config()?;
+
+        let shutdown_timeout = arguments
+            .shutdown_timeout
+            .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT);
+        if shutdown_timeout > MAX_SHUTDOWN_TIMEOUT {
+            return Err(AppError::Config(format!(
+                "shutdown_timeout must be between 0 and {MAX_SHUTDOWN_TIMEOUT}, got {shutdown_timeout}"
+            ))
+            .into());
+        }
+
+        let key_debounce_ms = arguments.key_debounce_ms.unwrap_or(DEFAULT_KEY_DEBOUNCE_MS);
+        if key_debounce_ms > MAX_KEY_DEBOUNCE_MS {
+            return Err(AppError::Config(format!(
+                "key_debounce_ms must be between 0 and {MAX_KEY_DEBOUNCE_MS}, got {key_debounce_ms}"
+            ))
+            .into());
+        }
+
+        // Each eviction has to leave a few pages loaded around the selection.
+        let max_loaded_messages = arguments
+            .max_loaded_messages
+            .unwrap_or(DEFAULT_MAX_LOADED_MESSAGES);
+        if max_loaded_messages != 0 && max_loaded_messages < MIN_MAX_LOADED_MESSAGES {
+            return Err(AppError::Config(format!(
+                "max_loaded_messages must be 0 (unlimited) or at least {MIN_MAX_LOADED_MESSAGES}, got {max_loaded_messages}"
+            ))
+            .into());
+        }
+
+        // The phonebook lists from the contact cache, so can't show more than it fetches.
+        if let Some(max_contacts) = arguments.phonebook_max_contacts {
+            let in_range = u64::try_from(max_contacts)
+                .is_ok_and(|max_contacts| (1..=CONTACT_CACHE_LIMIT).contains(&max_contacts));
+            if !in_range {
+                return Err(AppError::Config(format!(
+                    "phonebook_max_contacts must be between 1 and {CONTACT_CACHE_LIMIT}, got {max_contacts}"
+                ))
+                .into());
+            }
+        }
+
+        let view_cache_size = arguments.view_cache_size.unwrap_or(DEFAULT_VIEW_CACHE_SIZE);
+        if view_cache_size > MAX_VIEW_CACHE_SIZE {
+            return Err(AppError::Config(format!(
+                "view_cache_size must be between 0 and {MAX_VIEW_CACHE_SIZE}, got {view_cache_size}"
+            ))
+            .into());
+        }
+
+        let report_glyphs = match &arguments.report_glyphs {
+            Some(value) => Some(ReportGlyphs::parse(value).ok_or_else(|| {
+                AppError::Config(format!(
+                    "report_glyphs must be four comma separated icons (sent,received,retrying,failed), got '{value}'"
+                ))
+            })?),
+            None => None,
+        };
+
+        let parse_color = |name: &str, value: &Option<String>| {
+            match value {
+            Some(value) => Color::from_str(value).map(Some).map_err(|_| {
+                AppError::Config(format!(
+                    "{name} must be a color name (eg: green) or hex code (eg: #22c55e), got '{value}'"
+                ))
+            }),
+            None => Ok(None),
+        }
+        };
+        let action_colors = ActionColors {
+            primary: parse_color("action_primary", &arguments.action_primary)?,
+            danger: parse_color("action_danger", &arguments.action_danger)?,
+        };
+
+        if let Some(send_timeout) = arguments.send_timeout {
+            if !(1..=MAX_SEND_TIMEOUT).contains(&send_timeout) {
+                return Err(AppError::Config(format!(
+                    "send_timeout must be between 1 and {MAX_SEND_TIMEOUT}, got {send_timeout}"
+                ))
+                .into());
+            }
+        }
+
+        let verbose = arguments.verbose.unwrap_or(false);
+
+        // Invalid timestamp options fall back to defaults, with errors shown once started.
+        let (timestamps, timestamp_errors) = TimestampFormatter::from_config(
+            arguments.timezone.as_deref(),
+            arguments.date_format.as_deref(),
+            arguments.time_format.as_deref(),
+        );
+
+        // Printing the config needs the merged arguments, not just the client config, and
+        // shouldn't stop at a bad certificate which it reports once everything is printed.
+        let ((client, certificate_warnings), headless) = if print_config {
+            let (client, client_error) = match Self::create_sms_config(&arguments) {
+                Ok(client) => (client, None),
+                Err(e) => (
+                    (
+                        ClientConfig::http_only(Self::server_uris(&arguments).0),
+                        Vec::new(),
+                    ),
+                    Some(e.to_string()),
+                ),
+            };
+            let command = HeadlessCommand::PrintConfig {
+                arguments: Box::new(arguments.clone()),
+                client_error,
+            };
+            (client, Some(command))
+        } else {
+            (Self::create_sms_config(&arguments)?, headless)
+        };
         Ok(Self {
-            client: Self::create_sms_config(&arguments)?,
+            client,
+            certificate_warnings,
+            client_arguments: arguments.clone(),
             theme: arguments.theme.unwrap_or_default(),
-            websocket: arguments.ws_enabled.unwrap_or(false),
+            action_colors,
+            websocket: arguments.ws_mode.unwrap_or_default(),
+            websocket_max_retries: arguments.ws_max_retries.unwrap_or(DEFAULT_WS_MAX_RETRIES),
+            shutdown_timeout: Duration::from_secs(shutdown_timeout),
+            server_host: arguments
+                .http_uri
+                .clone()
+                .or_else(|| arguments.host.clone())
+                .unwrap_or_else(|| "localhost:3000".to_string()),
+            server_uris: Self::server_uris(&arguments),
+            auth_configured: arguments.auth.is_some(),
+            key_debounce: Duration::from_millis(key_debounce_ms),
+            resize: !arguments.no_resize.unwrap_or(false),
+            mouse: !arguments.no_mouse.unwrap_or(false),
+            quiet: arguments.quiet.unwrap_or(false),
+            conversation_refresh: arguments
+                .conversation_refresh_seconds
+                .filter(|seconds| *seconds > 0)
+                .map(Duration::from_secs),
             starting_view,
+            headless,
+            options: AppOptions {
+                debug: arguments.debug.unwrap_or(false),
+                open_links: arguments.open_links.unwrap_or(false),
+                wrap_navigation: arguments.wrap_navigation.unwrap_or(false),
+                enter_sends: arguments.enter_sends.unwrap_or(false),
+                vim_keys: arguments.vim_keys.unwrap_or(false),
+                direction_colors: arguments.direction_colors.unwrap_or(false),
+                signal_details: !arguments.no_signal_details.unwrap_or(false),
+                content_newlines: arguments.content_newlines.unwrap_or_default(),
+                max_loaded_messages: (max_loaded_messages > 0).then_some(max_loaded_messages),
+                phonebook_max_contacts: arguments.phonebook_max_contacts,
+                view_cache_size,
+                send_timeout: arguments.send_timeout,
+                send_interval: Duration::from_millis(
+                    arguments
+                        .send_interval_ms
+                        .unwrap_or(DEFAULT_SEND_INTERVAL_MS),
+                ),
+                load_timeout: Duration::from_secs(
+                    arguments.load_timeout.unwrap_or(DEFAULT_LOAD_TIMEOUT),
+                ),
+                timestamps,
+            },
+            timestamp_errors,
+            log_file: arguments
+                .log_file
+                .clone()
+                .or_else(|| verbose.then(AppArguments::default_log_path)),
+            verbose,
+            ascii: arguments.ascii.unwrap_or(false),
+            report_glyphs,
+            spinner: arguments.spinner,
 
             #[cfg(feature = "sentry")]
             sentry: arguments.sentry,
         })
     }
 
-    fn create_sms_config(arguments: &AppArguments) -> Result<ClientConfig> {
+    /// Get the HTTP and (if enabled) WebSocket URIs, built from the host unless overridden.
+    pub fn server_uris(arguments: &AppArguments) -> (String, Option<String>) {
+        let http_uri = arguments.http_uri.as_ref().map_or_else(
+            || {
+                let (host, secure) = Self::host_and_security(arguments);
+                format!("http{secure}://{host}")
+            },
+            String::from,
+        );
+
+        let ws_enabled = arguments.ws_mode.unwrap_or_default() != WebSocketMode::Off;
+        let ws_uri = ws_enabled.then(|| Self::websocket_uri(arguments));
+
+        (http_uri, ws_uri)
+    }
+
+    /// The WebSocket URI that would be used, even if the WebSocket is disabled.
+    pub fn websocket_uri(arguments: &AppArguments) -> String {
+        arguments.ws_uri.as_ref().map_or_else(
+            || {
+                let (host, secure) = Self::host_and_security(arguments);
+                format!("ws{secure}://{host}/ws")
+            },
+            String::from,
+        )
+    }
+
+    fn host_and_security(arguments: &AppArguments) -> (String, &'static str) {
         let host = arguments
             .host
             .as_ref()
             .map_or_else(|| "localhost:3000".to_string(), String::from);
 
-        // Create SMS config.
         let secure = if arguments.ssl_certificate.is_some() {
             "s"
         } else {
             ""
         };
-        let mut client_config = ClientConfig::http_only(
-            arguments
-                .http_uri
-                .as_ref()
-                .map_or_else(|| format!("http{secure}://{host}"), String::from),
-        );
+        (host, secure)
+    }
+
+    /// Create the client config, along with warnings about certificates that were skipped.
+    pub fn create_sms_config(arguments: &AppArguments) -> Result<(ClientConfig, Vec<String>)> {
+        // Create SMS config.
+        let (http_uri, ws_uri) = Self::server_uris(arguments);
+        let mut client_config = ClientConfig::http_only(http_uri);
 
         // Websocket
-        if arguments.ws_enabled.unwrap_or(false) {
-            let ws_uri = arguments
-                .ws_uri
-                .as_ref()
-                .map_or_else(|| format!("ws{secure}://{host}/ws"), String::from);
+        if let Some(ws_uri) = ws_uri {
             client_config = client_config.add_websocket(WebSocketConfig::new(ws_uri));
         }
 
@@ -301,12 +1019,20 @@ impl TerminalConfig {
             client_config = client_config.with_auth(auth);
         }
 
-        // SSL certificate
+        // SSL certificate. Client certificates (mutual TLS) aren't supported, as sms-client's
+        // TLSConfig only holds the certificate to trust. That needs a
+        // `TLSConfig::with_identity(cert, key)` storing both paths, loaded as a
+        // `reqwest::Identity` in its HTTP client builder and added to the tungstenite connector
+        // in `ws::tls::create_connector` (`with_client_auth_cert` for rustls, a
+        // `native_tls::Identity` for native-tls). Then `--client-cert` and `--client-key` can be
+        // validated like `ssl_certificate` and passed through here.
+        let mut warnings = Vec::new();
         if let Some(certificate) = &arguments.ssl_certificate {
+            warnings = certificate::validate_certificate(certificate)?;
             client_config = client_config.add_tls(TLSConfig::new(certificate)?);
         }
 
-        Ok(client_config)
+        Ok((client_config, warnings))
     }
 }
 
@@ -348,37 +1074,118 @@ fn init_sentry(dsn: String) -> sentry::ClientInitGuard {
     ))
 }
 
+/// Restores the terminal when dropped, so it's left usable however `main` exits, and on
+/// panic before the report is printed, as a panic may not unwind back through `main`.
+struct TerminalGuard {
+    mouse: bool,
+}
+impl TerminalGuard {
+    fn install(mouse: bool) -> Self {
+        if mouse {
+            let _ = crossterm::execute!(std::io::stdout(), crossterm::event::EnableMouseCapture);
+        }
+
+        // Runs before the existing hooks (ratatui's, then color_eyre's report).
+        chain_panic_hook(move || restore_terminal(mouse));
+        Self { mouse }
+    }
+}
+
+/// Install a panic hook that runs `before`, then the previously installed hook.
+fn chain_panic_hook(before: impl Fn() + Send + Sync + 'static) {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        before();
+        hook(info);
+    }));
+}
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal(self.mouse);
+    }
+}
+
+fn restore_terminal(mouse: bool) {
+    // ratatui::restore doesn't know about mouse capture, so undo it first.
+    if mouse {
+        let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableMouseCapture);
+    }
+    ratatui::restore();
+}
+
 const STARTING_MIN_WIDTH: u16 = 160;
 const STARTING_MIN_HEIGHT: u16 = 50;
 
 fn main() -> Result<()> {
     color_eyre::install()?;
-    let config = TerminalConfig::parse()?;
+    let mut config = TerminalConfig::parse()?;
+    glyphs::init_glyphs(config.ascii, config.report_glyphs.clone(), config.spinner);
+    if let Some(path) = &config.log_file {
+        logging::init_logging(path, config.verbose)?;
+        tracing::info!("sms-terminal v{PKG_VERSION} starting");
+    }
 
     #[cfg(feature = "sentry")]
     let _sentry_guard = config.sentry.as_ref().map(|dsn| init_sentry(dsn.clone()));
 
-    let result = tokio::runtime::Builder::new_current_thread()
+    let runtime = tokio::runtime::Builder::new_current_thread()
         .enable_all()
-        .build()?
-        .block_on(async move {
-            let terminal = ratatui::init();
-            let should_resize = terminal
+        .build()?;
+
+    // Headless commands never touch the terminal, they just print and exit.
+    if let Some(command) = config.headless.take() {
+        if !runtime.block_on(headless::run(command, config))? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let terminal = ratatui::init();
+    let _guard = TerminalGuard::install(config.mouse);
+
+    // Debug builds can panic once the terminal is set up, to check by hand that the report
+    // is readable and the shell is left out of raw mode with mouse capture off.
+    #[cfg(debug_assertions)]
+    if std::env::var_os("SMS_TERMINAL_TEST_PANIC").is_some() {
+        panic!("Deliberate panic from SMS_TERMINAL_TEST_PANIC");
+    }
+    runtime.block_on(async move {
+        let should_resize = config.resize
+            && terminal
                 .size()
                 .ok()
                 .is_some_and(|s| STARTING_MIN_HEIGHT > s.height || STARTING_MIN_WIDTH > s.width);
 
-            if should_resize {
-                let _ =
-                    crossterm::execute!(std::io::stdout(), crossterm::terminal::SetSize(160, 50));
-            }
+        if should_resize {
+            let _ = crossterm::execute!(std::io::stdout(), crossterm::terminal::SetSize(160, 50));
+        }
 
-            // Get the starting view from arguments.
-            let starting_view = config.starting_view.clone().unwrap_or_default();
+        // Get the starting view from arguments.
+        let starting_view = config.starting_view.clone().unwrap_or_default();
 
-            App::new(config)?.run(terminal, starting_view).await
-        });
+        App::new(config)?.run(terminal, starting_view).await
+    })
+}
 
-    ratatui::restore();
-    result
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn panic_hook_restores_before_reporting() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let original = std::panic::take_hook();
+
+        let report = Arc::clone(&calls);
+        std::panic::set_hook(Box::new(move |_| report.lock().unwrap().push("report")));
+        let restore = Arc::clone(&calls);
+        chain_panic_hook(move || restore.lock().unwrap().push("restore"));
+
+        let result = std::panic::catch_unwind(|| panic!("deliberate"));
+        std::panic::set_hook(original);
+
+        assert!(result.is_err());
+        assert_eq!(*calls.lock().unwrap(), ["restore", "report"]);
+    }
 }
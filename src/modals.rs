@@ -2,7 +2,8 @@ use crate::app::AppContext;
 use crate::theme::Theme;
 use crate::types::AppAction;
 use crate::ui::modals::ModalComponent;
-use crossterm::event::KeyEvent;
+use crate::ui::views::ViewStateRequest;
+use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::Frame;
 
 /// Determines how a modal should be loaded after it's set.
@@ -17,6 +18,8 @@ pub enum ModalLoadBehaviour {
 pub enum ModalMetadata {
     SendMessage(String, String), // phone_number, message_content
     PhoneNumber(String),
+    MessageId(i64),
+    Reauthenticate(Box<ViewStateRequest>), // request to retry
     None,
 }
 
@@ -25,6 +28,7 @@ pub enum ModalResponse {
     Dismissed,
     Confirmed,
     TextInput(Option<String>),
+    Reload, // Run the modal's loader again, eg: to refresh its data
 }
 
 #[derive(Debug)]
@@ -57,6 +61,11 @@ impl AppModal {
         self.inner.handle_key(key)
     }
 
+    #[inline]
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<ModalResponse> {
+        self.inner.handle_mouse(mouse)
+    }
+
     #[inline]
     pub fn load(&self) -> ModalLoadBehaviour {
         self.inner.load()
@@ -0,0 +1,186 @@
+use unicode_general_category::{get_general_category, GeneralCategory};
+
+use crate::glyphs::glyphs;
+use crate::types::ContentNewlines;
+
+/// Make untrusted text (eg: message content or a sender) safe to draw in the terminal or
+/// write out. Escape sequences are removed first, then only printable characters are kept:
+/// letters, marks, numbers, punctuation, symbols and spaces. Tabs become spaces, and
+/// newlines are handled by the given mode. Everything else, including lone escapes,
+/// other control characters and invisible formatting characters, is dropped.
+pub fn sanitize_text(text: &str, newlines: ContentNewlines) -> String {
+    let stripped = strip_escapes(text);
+    let mut sanitized = String::with_capacity(stripped.len());
+    for c in stripped.chars() {
+        match c {
+            '\n' => match newlines {
+                ContentNewlines::Strip => {}
+                ContentNewlines::Symbol => {
+                    sanitized.push(' ');
+                    sanitized.push_str(glyphs().newline);
+                    sanitized.push(' ');
+                }
+                ContentNewlines::Keep => sanitized.push('\n'),
+            },
+            '\t' => sanitized.push(' '),
+            c if is_printable(c) => sanitized.push(c),
+            _ => {}
+        }
+    }
+    sanitized
+}
+
+/// Remove terminal escape sequences, in both their 7-bit (`ESC x`) and C1 forms: CSI up to
+/// its final byte, and OSC, DCS, SOS, PM and APC strings up to BEL or ST. Any other escape
+/// is dropped with its intermediate and final bytes. An unterminated sequence runs to the
+/// end of the text, so nothing it was hiding is shown.
+fn strip_escapes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let is_csi = match c {
+            '\u{1b}' => match chars.next() {
+                Some('[') => true,
+                Some(']' | 'P' | 'X' | '^' | '_') => false,
+                Some(' '..='/') => {
+                    // Intermediate bytes, then a final byte, eg: "ESC ( B"
+                    while chars.next().is_some_and(|c| matches!(c, ' '..='/')) {}
+                    continue;
+                }
+                _ => continue,
+            },
+            '\u{9b}' => true,
+            '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => false,
+            c => {
+                stripped.push(c);
+                continue;
+            }
+        };
+
+        if is_csi {
+            // Parameter and intermediate bytes, up to the final byte
+            for c in chars.by_ref() {
+                if matches!(c, '@'..='~') {
+                    break;
+                }
+            }
+        } else {
+            while let Some(c) = chars.next() {
+                match c {
+                    '\u{7}' | '\u{9c}' => break,
+                    '\u{1b}' => {
+                        chars.next_if_eq(&'\\');
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    stripped
+}
+
+fn is_printable(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::UppercaseLetter
+            | GeneralCategory::LowercaseLetter
+            | GeneralCategory::TitlecaseLetter
+            | GeneralCategory::ModifierLetter
+            | GeneralCategory::OtherLetter
+            | GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark
+            | GeneralCategory::DecimalNumber
+            | GeneralCategory::LetterNumber
+            | GeneralCategory::OtherNumber
+            | GeneralCategory::ConnectorPunctuation
+            | GeneralCategory::DashPunctuation
+            | GeneralCategory::OpenPunctuation
+            | GeneralCategory::ClosePunctuation
+            | GeneralCategory::InitialPunctuation
+            | GeneralCategory::FinalPunctuation
+            | GeneralCategory::OtherPunctuation
+            | GeneralCategory::MathSymbol
+            | GeneralCategory::CurrencySymbol
+            | GeneralCategory::ModifierSymbol
+            | GeneralCategory::OtherSymbol
+            | GeneralCategory::SpaceSeparator
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize(text: &str) -> String {
+        sanitize_text(text, ContentNewlines::Strip)
+    }
+
+    #[test]
+    fn strips_csi() {
+        assert_eq!(sanitize("\x1b[1;31mred\x1b[0m text"), "red text");
+        assert_eq!(sanitize("\x1b[2J\x1b[Hcleared"), "cleared");
+        assert_eq!(sanitize("\u{9b}31mred"), "red");
+    }
+
+    #[test]
+    fn strips_osc_terminated_by_bel() {
+        assert_eq!(sanitize("\x1b]0;fake title\x07hello"), "hello");
+        assert_eq!(sanitize("\x1b]52;c;ZXZpbA==\x07paste"), "paste");
+    }
+
+    #[test]
+    fn strips_osc_terminated_by_st() {
+        let hyperlink = "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\";
+        assert_eq!(sanitize(hyperlink), "link");
+        assert_eq!(sanitize("\u{9d}0;title\u{9c}hi"), "hi");
+    }
+
+    #[test]
+    fn strips_unterminated_sequences() {
+        assert_eq!(sanitize("visible\x1b]0;hidden"), "visible");
+        assert_eq!(sanitize("visible\x1b[31"), "visible");
+        assert_eq!(sanitize("visible\x1b"), "visible");
+    }
+
+    #[test]
+    fn strips_other_escapes() {
+        assert_eq!(sanitize("\x1bcreset"), "reset");
+        assert_eq!(sanitize("\x1b(Bcharset"), "charset");
+        assert_eq!(sanitize("\x1bPdcs\x1b\\after"), "after");
+    }
+
+    #[test]
+    fn strips_c1_controls() {
+        assert_eq!(sanitize("a\u{80}b\u{85}c\u{9c}d"), "abcd");
+        for introducer in ['\u{90}', '\u{98}', '\u{9d}', '\u{9e}', '\u{9f}'] {
+            let text = format!("ok{introducer}hidden\u{9c}");
+            assert_eq!(sanitize(&text), "ok");
+        }
+    }
+
+    #[test]
+    fn strips_bidi_and_invisible_formatting() {
+        assert_eq!(sanitize("abc\u{202e}fed\u{202c}"), "abcfed");
+        assert_eq!(sanitize("\u{2066}a\u{2069}\u{200f}b\u{200b}"), "ab");
+    }
+
+    #[test]
+    fn strips_other_controls() {
+        assert_eq!(sanitize("a\x07b\x08c\rd\0e"), "abcde");
+        assert_eq!(sanitize("tab\there"), "tab here");
+    }
+
+    #[test]
+    fn handles_newlines_by_mode() {
+        assert_eq!(sanitize_text("a\nb", ContentNewlines::Strip), "ab");
+        assert_eq!(sanitize_text("a\nb", ContentNewlines::Keep), "a\nb");
+    }
+
+    #[test]
+    fn keeps_printable_text() {
+        let text = "Héllo, 你好 👋 (£5 + 10%) [ok]";
+        assert_eq!(sanitize(text), text);
+    }
+}
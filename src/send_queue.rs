@@ -0,0 +1,69 @@
+use crate::error::HttpStatus;
+use std::fmt::Display;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Spaces out message sends so several in quick succession don't hit the server's rate limit.
+///
+/// The queue is the mutex itself: each send holds it for its whole attempt, and tokio's
+/// mutex wakes waiters in the order they started waiting. So sends from separate tasks
+/// (eg: compose spawns one per message) run one at a time in FIFO order, each starting
+/// at least `min_interval` after the previous one finished, without a dedicated worker
+/// task. If the server still rate limits a send, it is retried with an increasing backoff
+/// while holding its place, so the sends queued behind it wait too.
+#[derive(Debug)]
+pub struct SendQueue {
+    min_interval: Duration,
+    last_send: Mutex<Option<Instant>>,
+}
+impl SendQueue {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_send: Mutex::new(None),
+        }
+    }
+
+    /// Is a send running or waiting for its turn.
+    pub fn is_busy(&self) -> bool {
+        self.last_send.try_lock().is_err()
+    }
+
+    /// Wait until every send queued so far has finished, by taking a turn after them.
+    pub async fn flush(&self) {
+        let _turn = self.last_send.lock().await;
+    }
+
+    /// Wait for this send's turn, then run it, eg: `queue.send(|| http.send_sms(&message))`.
+    pub async fn send<T, E, F, Fut>(&self, send: F) -> Result<T, E>
+    where
+        E: Display,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut last_send = self.last_send.lock().await;
+        let mut retries = 0;
+        loop {
+            if let Some(last) = *last_send {
+                tokio::time::sleep_until((last + self.min_interval).into()).await;
+            }
+
+            let result = send().await;
+            *last_send = Some(Instant::now());
+            match result {
+                Err(e)
+                    if HttpStatus::TooManyRequests.matches(&e)
+                        && retries < MAX_RATE_LIMIT_RETRIES =>
+                {
+                    tokio::time::sleep(RATE_LIMIT_BACKOFF * 2_u32.pow(retries)).await;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::error::{AppError, AppResult};
+use crate::types::MessageLabel;
+use crate::AppArguments;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PersistedState {
+    #[serde(default)]
+    conversation_order: HashMap<String, bool>, // number -> reversed
+
+    #[serde(default)]
+    archived: BTreeSet<String>, // numbers hidden from the phonebook
+
+    #[serde(default)]
+    last_seen: HashMap<String, i64>, // number -> unix time of newest message seen
+
+    #[serde(default)]
+    labels: BTreeMap<String, MessageLabel>, // message_id -> label, as JSON keys are strings
+
+    #[serde(default)]
+    signal_details: Option<bool>, // None until toggled in device info
+}
+
+/// Per-user state remembered between sessions, kept separately from the config file.
+/// Views read and update it through the `AppContext`, and the `App` saves any changes
+/// periodically and on exit. If the state file can't be used it's kept in memory only.
+#[derive(Debug, Default)]
+pub struct StateStore {
+    inner: RwLock<PersistedState>,
+    path: Option<PathBuf>, // None when in-memory only
+    dirty: AtomicBool,
+}
+impl StateStore {
+    /// Load the state file, migrating an older TOML state file if it's missing, or
+    /// otherwise starting empty. An unreadable or invalid file is left untouched, and
+    /// the state is kept in memory only with an error returned.
+    pub fn load() -> (Self, Option<String>) {
+        Self::load_from(Self::path(), &Self::legacy_paths())
+    }
+
+    fn load_from(path: PathBuf, legacy_paths: &[PathBuf]) -> (Self, Option<String>) {
+        let mut migrated = false;
+        let (state, path, error) = match std::fs::read_to_string(&path) {
+            Ok(data) => match serde_json::from_str(&data) {
+                Ok(state) => (state, Some(path), None),
+                Err(e) => (
+                    PersistedState::default(),
+                    None,
+                    Some(format!(
+                        "Failed to parse state file, changes won't be saved: {e}"
+                    )),
+                ),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let legacy = Self::read_legacy(legacy_paths);
+                migrated = legacy.is_some();
+                (legacy.unwrap_or_default(), Some(path), None)
+            }
+            Err(e) => (
+                PersistedState::default(),
+                None,
+                Some(format!(
+                    "Failed to read state file, changes won't be saved: {e}"
+                )),
+            ),
+        };
+
+        let store = Self {
+            inner: RwLock::new(state),
+            path,
+            dirty: AtomicBool::new(migrated), // Saved to the new path with the next changes
+        };
+        (store, error)
+    }
+
+    /// Read the first older TOML state file that parses. They're left in place, as
+    /// the JSON state file takes over once it has been saved.
+    fn read_legacy(paths: &[PathBuf]) -> Option<PersistedState> {
+        paths.iter().find_map(|path| {
+            let data = std::fs::read_to_string(path).ok()?;
+            match toml::from_str(&data) {
+                Ok(state) => {
+                    tracing::info!("Migrating state from {}", path.display());
+                    Some(state)
+                }
+                Err(e) => {
+                    tracing::warn!("Ignoring invalid state file {}: {e}", path.display());
+                    None
+                }
+            }
+        })
+    }
+
+    /// Where older versions kept the state as TOML: at the current path, and before
+    /// that next to the config file.
+    fn legacy_paths() -> Vec<PathBuf> {
+        let config_path = AppArguments::config_path();
+        let file_name = if config_path.file_name() == Some("config.toml".as_ref()) {
+            "state.toml"
+        } else {
+            "sms-terminal-state.toml"
+        };
+
+        let mut paths = vec![
+            Self::path().with_extension("toml"),
+            config_path.with_file_name(file_name),
+        ];
+        paths.dedup();
+        paths
+    }
+
+    pub fn path() -> PathBuf {
+        // Check if local state exists first, including one still to be migrated
+        let local = PathBuf::from("sms-terminal-state.json");
+        if local.exists() || local.with_extension("toml").exists() {
+            return local;
+        }
+
+        // On Windows, keep it alongside the config in Local AppData
+        #[cfg(windows)]
+        {
+            if let Ok(appdata) = std::env::var("LOCALAPPDATA") {
+                return PathBuf::from(appdata)
+                    .join("sms-terminal")
+                    .join("state.json");
+            }
+        }
+
+        // On Unix, use the XDG state directory
+        #[cfg(not(windows))]
+        {
+            if let Ok(state_home) = std::env::var("XDG_STATE_HOME") {
+                return PathBuf::from(state_home)
+                    .join("sms-terminal")
+                    .join("state.json");
+            }
+            if let Ok(home) = std::env::var("HOME") {
+                return PathBuf::from(home)
+                    .join(".local")
+                    .join("state")
+                    .join("sms-terminal")
+                    .join("state.json");
+            }
+        }
+
+        // Final fallback to local directory
+        local
+    }
+
+    /// Is the state kept in memory only, as the state file couldn't be used.
+    pub fn is_in_memory(&self) -> bool {
+        self.path.is_none()
+    }
+
+    /// Write the state file if anything has changed since it was last saved.
+    pub fn save_if_dirty(&self) -> AppResult<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty.swap(false, Ordering::AcqRel) {
+            return Ok(());
+        }
+
+        let result = self.save(path);
+        if result.is_err() {
+            // Try again next time.
+            self.dirty.store(true, Ordering::Release);
+        }
+        result
+    }
+
+    fn save(&self, path: &Path) -> AppResult<()> {
+        let content = {
+            let inner = self
+                .inner
+                .read()
+                .map_err(|_| AppError::Config("State lock was poisoned".to_string()))?;
+            serde_json::to_string_pretty(&*inner)
+                .map_err(|e| AppError::Config(format!("Failed to serialize state: {e}")))?
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AppError::Config(format!("Failed to create state directories: {e}"))
+            })?;
+        }
+
+        // Write a temporary file alongside and rename it over the state file, so a crash
+        // mid-write can't leave it truncated.
+        let temp_path = path.with_extension("json.tmp");
+        let result = std::fs::File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(content.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result.map_err(|e| AppError::Config(format!("Failed to write state file: {e}")))
+    }
+
+    /// Apply a change to the state, marking it to be saved.
+    fn update(&self, f: impl FnOnce(&mut PersistedState)) {
+        if let Ok(mut inner) = self.inner.write() {
+            f(&mut inner);
+            self.dirty.store(true, Ordering::Release);
+        }
+    }
+
+    /// Is the conversation with a number shown oldest first.
+    pub fn is_reversed(&self, number: &str) -> bool {
+        self.inner
+            .read()
+            .ok()
+            .and_then(|inner| inner.conversation_order.get(number).copied())
+            .unwrap_or(false)
+    }
+
+    /// Remember the order for a conversation.
+    pub fn set_reversed(&self, number: &str, reversed: bool) {
+        self.update(|inner| {
+            if reversed {
+                inner.conversation_order.insert(number.to_string(), true);
+            } else {
+                inner.conversation_order.remove(number);
+            }
+        });
+    }
+
+    /// The unix time of the newest message seen in a conversation, if it has been opened.
+    pub fn last_seen(&self, number: &str) -> Option<i64> {
+        self.inner
+            .read()
+            .ok()
+            .and_then(|inner| inner.last_seen.get(number).copied())
+    }
+
+    /// Mark a conversation as seen up to a message time, never moving backwards.
+    pub fn set_last_seen(&self, number: &str, timestamp: i64) {
+        if self.last_seen(number).is_some_and(|seen| seen >= timestamp) {
+            return;
+        }
+        self.update(|inner| {
+            inner.last_seen.insert(number.to_string(), timestamp);
+        });
+    }
+
+    /// The local label for a message, by its server ID.
+    pub fn message_label(&self, message_id: i64) -> Option<MessageLabel> {
+        self.inner
+            .read()
+            .ok()
+            .and_then(|inner| inner.labels.get(&message_id.to_string()).copied())
+    }
+
+    /// Label a message, or remove its label with `None`.
+    pub fn set_message_label(&self, message_id: i64, label: Option<MessageLabel>) {
+        self.update(|inner| match label {
+            Some(label) => {
+                inner.labels.insert(message_id.to_string(), label);
+            }
+            None => {
+                inner.labels.remove(&message_id.to_string());
+            }
+        });
+    }
+
+    /// Whether device info shows the technical signal lines, if it's ever been toggled.
+    pub fn signal_details(&self) -> Option<bool> {
+        self.inner
+            .read()
+            .ok()
+            .and_then(|inner| inner.signal_details)
+    }
+
+    /// Remember whether device info shows the technical signal lines.
+    pub fn set_signal_details(&self, shown: bool) {
+        self.update(|inner| inner.signal_details = Some(shown));
+    }
+
+    /// Is a number archived, hiding it from the phonebook's recent contacts.
+    pub fn is_archived(&self, number: &str) -> bool {
+        self.inner
+            .read()
+            .is_ok_and(|inner| inner.archived.contains(number))
+    }
+
+    /// Archive or unarchive a number.
+    pub fn set_archived(&self, number: &str, archived: bool) {
+        self.update(|inner| {
+            if archived {
+                inner.archived.insert(number.to_string());
+            } else {
+                inner.archived.remove(number);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory for a test, removed again when dropped.
+    struct TempDir(PathBuf);
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("sms-terminal-state-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_json() {
+        let dir = TempDir::new("round-trip");
+        let path = dir.0.join("state.json");
+
+        let (store, error) = StateStore::load_from(path.clone(), &[]);
+        assert_eq!(error, None);
+        store.set_reversed("+441234567890", true);
+        store.set_message_label(42, Some(MessageLabel::Important));
+        store.save_if_dirty().unwrap();
+
+        assert!(serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(&path).unwrap()
+        )
+        .is_ok());
+        assert!(!path.with_extension("json.tmp").exists());
+
+        let (store, error) = StateStore::load_from(path, &[]);
+        assert_eq!(error, None);
+        assert!(store.is_reversed("+441234567890"));
+        assert_eq!(store.message_label(42), Some(MessageLabel::Important));
+    }
+
+    #[test]
+    fn migrates_legacy_toml() {
+        let dir = TempDir::new("migrate");
+        let path = dir.0.join("state.json");
+        let legacy = dir.0.join("state.toml");
+        std::fs::write(&legacy, "archived = [\"+441234567890\"]\n").unwrap();
+
+        let legacy_paths = [dir.0.join("missing.toml"), legacy];
+        let (store, error) = StateStore::load_from(path.clone(), &legacy_paths);
+        assert_eq!(error, None);
+        assert!(store.is_archived("+441234567890"));
+
+        // Saved to the new path without any further changes.
+        store.save_if_dirty().unwrap();
+        let (store, _) = StateStore::load_from(path, &[]);
+        assert!(store.is_archived("+441234567890"));
+    }
+
+    #[test]
+    fn invalid_file_is_kept_in_memory_only() {
+        let dir = TempDir::new("invalid");
+        let path = dir.0.join("state.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let (store, error) = StateStore::load_from(path.clone(), &[]);
+        assert!(error.is_some());
+        assert!(store.is_in_memory());
+
+        store.set_archived("+441234567890", true);
+        store.save_if_dirty().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not json");
+    }
+}
@@ -41,12 +41,28 @@ impl PresetTheme {
         }
     }
 
+    /// The default color for primary actions (eg: Yes, OK). The accent, unless the theme's
+    /// accent could be mistaken for a warning or danger, in which case green.
+    pub const fn action_primary(self) -> Color {
+        match self {
+            PresetTheme::Red | PresetTheme::Amber | PresetTheme::Pink => tailwind::GREEN.c500,
+            _ => self.palette().c400,
+        }
+    }
+
     #[inline]
     pub const fn as_index(self) -> usize {
         self as usize
     }
 }
 
+/// Configured overrides for the action button colors, None for the theme's default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActionColors {
+    pub primary: Option<Color>,
+    pub danger: Option<Color>,
+}
+
 pub struct Theme {
     // Base colors
     pub bg: Color,
@@ -60,11 +76,16 @@ pub struct Theme {
     pub text_primary: Color,
     pub text_muted: Color,
     pub text_accent: Color,
-    pub text_error: Color,
+
+    // Buttons
+    pub action_primary: Color,
+    pub action_danger: Color,
 
     // Table specific
     pub row_normal_bg: Color,
     pub row_alt_bg: Color,
+    pub row_incoming_bg: Color,
+    pub row_outgoing_bg: Color,
     pub row_selected_fg: Color,
     pub column_selected_fg: Color,
     pub cell_selected_fg: Color,
@@ -120,11 +141,16 @@ impl Theme {
             text_primary,
             text_muted: palette.c400,
             text_accent,
-            text_error,
+
+            // Buttons
+            action_primary: text_accent,
+            action_danger: text_error,
 
             // Table
             row_normal_bg: palette.c950,
             row_alt_bg: palette.c900,
+            row_incoming_bg: tailwind::SLATE.c900,
+            row_outgoing_bg: palette.c900,
             row_selected_fg: palette.c300,
             column_selected_fg: palette.c300,
             cell_selected_fg: palette.c500,
@@ -170,11 +196,16 @@ impl Theme {
             text_primary: SLATE_200,
             text_muted: SLATE_500,
             text_accent,
-            text_error: RED_400,
+
+            // Buttons
+            action_primary: text_accent,
+            action_danger: RED_400,
 
             // Table
             row_normal_bg: SLATE_950,
             row_alt_bg: SLATE_900,
+            row_incoming_bg: SLATE_900,
+            row_outgoing_bg: palette.c950,
             row_selected_fg: palette.c400,
             column_selected_fg: palette.c400,
             cell_selected_fg: palette.c600,
@@ -194,6 +225,15 @@ impl Theme {
     }
 }
 
+impl Theme {
+    /// Use the preset's action colors, or the configured ones if set.
+    fn with_action_colors(mut self, preset: PresetTheme, colors: ActionColors) -> Self {
+        self.action_primary = colors.primary.unwrap_or(preset.action_primary());
+        self.action_danger = colors.danger.unwrap_or(self.action_danger);
+        self
+    }
+}
+
 impl From<&PresetTheme> for Theme {
     #[inline]
     fn from(preset: &PresetTheme) -> Self {
@@ -207,13 +247,17 @@ pub struct ThemeManager {
     dynamic_themes: [Option<Rc<Theme>>; PresetTheme::COUNT],
     current_preset: PresetTheme,
     current_theme: Rc<Theme>,
+    action_colors: ActionColors,
 }
 impl ThemeManager {
-    pub fn with_preset(preset: PresetTheme) -> Self {
+    pub fn with_preset(preset: PresetTheme, action_colors: ActionColors) -> Self {
         const NONE: Option<Rc<Theme>> = None;
 
         let modify_background = true;
-        let current_theme = Rc::new(Theme::with_mode(&preset.palette(), modify_background));
+        let current_theme = Rc::new(
+            Theme::with_mode(&preset.palette(), modify_background)
+                .with_action_colors(preset, action_colors),
+        );
 
         let mut dynamic_themes = [NONE; PresetTheme::COUNT];
         dynamic_themes[preset.as_index()] = Some(current_theme.clone());
@@ -224,6 +268,7 @@ impl ThemeManager {
             dynamic_themes,
             current_preset: preset,
             current_theme,
+            action_colors,
         }
     }
 
@@ -232,6 +277,16 @@ impl ThemeManager {
         &self.current_theme
     }
 
+    #[inline]
+    pub fn current_preset(&self) -> PresetTheme {
+        self.current_preset
+    }
+
+    #[inline]
+    pub fn modify_background(&self) -> bool {
+        self.modify_background
+    }
+
     #[inline]
     pub fn next(&mut self) {
         let next_index = (self.current_preset as u8 + 1) % PresetTheme::COUNT as u8;
@@ -255,10 +310,10 @@ impl ThemeManager {
 
         self.current_theme = theme_cache[index]
             .get_or_insert_with(|| {
-                Rc::new(Theme::with_mode(
-                    &self.current_preset.palette(),
-                    self.modify_background,
-                ))
+                Rc::new(
+                    Theme::with_mode(&self.current_preset.palette(), self.modify_background)
+                        .with_action_colors(self.current_preset, self.action_colors),
+                )
             })
             .clone();
     }
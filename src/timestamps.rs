@@ -0,0 +1,126 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::fmt::Display;
+
+/// The timezone all timestamps are displayed in, defaulting to the system local zone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisplayTimezone {
+    #[default]
+    Local,
+    Named(Tz),
+}
+impl DisplayTimezone {
+    /// Parse an IANA timezone name (e.g. `Europe/London`).
+    pub fn parse(name: &str) -> Result<Self, String> {
+        name.parse::<Tz>()
+            .map(Self::Named)
+            .map_err(|e| format!("Invalid timezone '{name}': {e}"))
+    }
+
+    /// Convert a unix timestamp (seconds) into a UTC datetime.
+    pub fn from_unix(timestamp: impl Into<i64>) -> Option<DateTime<Utc>> {
+        Utc.timestamp_opt(timestamp.into(), 0).single()
+    }
+
+    /// Format a datetime in this timezone, all displayed timestamps should go through here.
+    pub fn format(&self, datetime: DateTime<Utc>, format: &str) -> String {
+        match self {
+            Self::Local => datetime.with_timezone(&Local).format(format).to_string(),
+            Self::Named(tz) => datetime.with_timezone(tz).format(format).to_string(),
+        }
+    }
+}
+impl Display for DisplayTimezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local => write!(f, "Local"),
+            Self::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+const DEFAULT_DATE_FORMAT: &str = "%d/%m/%y";
+const DEFAULT_TIME_FORMAT: &str = "%H:%M";
+const DEFAULT_PRECISE_TIME_FORMAT: &str = "%H:%M:%S";
+
+/// Formats all displayed timestamps with the configured timezone and formats.
+/// If no time format is configured, places needing precision (e.g. delivery
+/// reports) include seconds, otherwise the configured time format is used everywhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampFormatter {
+    pub timezone: DisplayTimezone,
+    date_format: String,
+    time_format: Option<String>,
+}
+impl TimestampFormatter {
+    /// Create from config values, falling back to defaults for any invalid
+    /// values. Returns a list of errors to show for the invalid values.
+    pub fn from_config(
+        timezone: Option<&str>,
+        date_format: Option<&str>,
+        time_format: Option<&str>,
+    ) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+
+        let timezone = match timezone.map(DisplayTimezone::parse) {
+            Some(Ok(timezone)) => timezone,
+            Some(Err(e)) => {
+                errors.push(format!("{e}, using local time instead."));
+                DisplayTimezone::Local
+            }
+            None => DisplayTimezone::Local,
+        };
+
+        let mut validated = |name: &str, format: Option<&str>| match format {
+            Some(format) if Self::is_valid_format(format) => Some(format.to_string()),
+            Some(format) => {
+                errors.push(format!("Invalid {name} '{format}', using default."));
+                None
+            }
+            None => None,
+        };
+        let date_format = validated("date format", date_format)
+            .unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string());
+        let time_format = validated("time format", time_format);
+
+        let formatter = Self {
+            timezone,
+            date_format,
+            time_format,
+        };
+        (formatter, errors)
+    }
+
+    /// Check a strftime string only contains valid specifiers and
+    /// produces some output, since formatting an invalid one panics.
+    fn is_valid_format(format: &str) -> bool {
+        !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+            && !Utc::now().format(format).to_string().trim().is_empty()
+    }
+
+    /// Format a full date and time, as shown in tables.
+    pub fn date_time(&self, datetime: DateTime<Utc>) -> String {
+        let time_format = self.time_format.as_deref().unwrap_or(DEFAULT_TIME_FORMAT);
+        self.timezone
+            .format(datetime, &format!("{} {time_format}", self.date_format))
+    }
+
+    /// Format just the time, including seconds unless a time format is configured.
+    pub fn precise_time(&self, datetime: DateTime<Utc>) -> String {
+        let time_format = self
+            .time_format
+            .as_deref()
+            .unwrap_or(DEFAULT_PRECISE_TIME_FORMAT);
+        self.timezone.format(datetime, time_format)
+    }
+}
+impl Default for TimestampFormatter {
+    fn default() -> Self {
+        Self {
+            timezone: DisplayTimezone::Local,
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            time_format: None,
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
 use sms_client::types::sms::SmsMessage;
 use std::time::{Duration, Instant};
 
+use crate::device_status::DeviceStatus;
+use crate::glyphs::glyphs;
 use crate::modals::AppModal;
 use crate::ui::notifications::NotificationType;
-use crate::ui::views::ViewStateRequest;
+use crate::ui::views::{MessagesPage, ViewStateRequest};
 
 #[derive(Debug, PartialEq)]
 pub enum AppAction {
@@ -13,12 +16,41 @@ pub enum AppAction {
         dismiss_modal: bool,
     },
     SetModal(Option<AppModal>),
+    ReloadModal,
     HandleMessage(SmsMessage),
+    AddPendingMessage {
+        phone_number: String,
+        pending_id: i64, // Negative, see `next_pending_id`
+        content: String,
+        parts: usize,
+    },
+    ResolvePendingMessage {
+        phone_number: String,
+        pending_id: i64,
+        message_id: Option<i64>, // None if the send failed
+    },
+    MergeMessages {
+        phone_number: String,
+        messages: Vec<SmsMessage>, // newest first
+    },
+    MessagesPage(MessagesPage),
     ShowNotification(NotificationType),
     ShowError {
         message: String,
         dismissible: bool,
     },
+    ShowAbout,
+    EnableWebSocket,
+    UpdateDeviceStatus(DeviceStatus),
+    RefreshDeviceStatus,
+    SetFriendlyName {
+        phone_number: String,
+        friendly_name: String,
+    },
+    Reauthenticate {
+        token: String,
+        retry: Box<ViewStateRequest>,
+    },
     Exit,
 
     /// Unimplemented, but left to hopefully spur me into finishing
@@ -26,6 +58,100 @@ pub enum AppAction {
     DeliveryFailure(String),
 }
 
+/// How newlines in message content are shown in the messages table. Other control and
+/// format characters are always removed, so no mode can emit terminal escapes.
+///
+/// Table rows have a fixed height, with one blank line above and below the content:
+/// - `Strip` removes newlines, joining the lines together (the default).
+/// - `Symbol` replaces them with `⏎`, so line breaks are visible without using rows.
+/// - `Keep` preserves them, so content is shown as sent but long messages are cut off
+///   sooner, as each line break uses one of the row's content lines.
+#[derive(clap::ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentNewlines {
+    #[default]
+    Strip,
+    Symbol,
+    Keep,
+}
+
+/// Whether to connect the WebSocket for live updates.
+///
+/// `Auto` (the default) tries to connect on startup, and if that first connection
+/// fails it gives up and carries on without live updates, as `Off` would.
+#[derive(clap::ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebSocketMode {
+    On,
+    Off,
+    #[default]
+    Auto,
+}
+impl WebSocketMode {
+    /// The mode an explicit `ws_enabled` value asks for.
+    pub fn from_enabled(enabled: bool) -> Self {
+        if enabled {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
+/// The frames of the loading spinner. Braille is the default, but some fonts draw it
+/// poorly, so `Line` is used in ASCII mode unless a style is picked explicitly.
+#[derive(clap::ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SpinnerStyle {
+    #[default]
+    Braille,
+    Dots,
+    Line,
+    Arrows,
+}
+impl SpinnerStyle {
+    pub const fn frames(self) -> &'static [char] {
+        match self {
+            Self::Braille => &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'],
+            Self::Dots => &['·', '•', '●', '•'],
+            Self::Line => &['|', '/', '-', '\\'],
+            Self::Arrows => &['←', '↖', '↑', '↗', '→', '↘', '↓', '↙'],
+        }
+    }
+}
+
+/// A local label for a message, kept in the state file rather than on the server.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MessageLabel {
+    Important,
+    FollowUp,
+    Done,
+}
+impl MessageLabel {
+    pub const ALL: [Self; 3] = [Self::Important, Self::FollowUp, Self::Done];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Important => "important",
+            Self::FollowUp => "follow-up",
+            Self::Done => "done",
+        }
+    }
+
+    pub fn glyph(self) -> &'static str {
+        match self {
+            Self::Important => glyphs().label_important,
+            Self::FollowUp => glyphs().label_follow_up,
+            Self::Done => glyphs().label_done,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|label| label.name() == name)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyPress {
     pub code: KeyCode,
@@ -42,43 +168,165 @@ impl From<KeyEvent> for KeyPress {
 
 /// Prevent long key presses etc from sending multiple `KeyPress` events.
 /// This was a particular issue when using WSL for some reason!
+///
+/// Events for the same key arriving within the debounce window are a burst, which is
+/// ignored for an initial delay and then let through at a steady rate. Slower repeats
+/// of the same key within `HOLD_GAP` (a second tap, or the OS key repeat starting after
+/// its own delay) are processed but keep the press going, so holding an arrow key
+/// scrolls smoothly instead of stalling again once the OS starts repeating.
 pub struct KeyDebouncer {
     last_key: Option<KeyPress>,
-    last_time: Instant,
+    last_time: Instant,      // last event for the key, processed or not
+    held_since: Instant,     // first event of the current press
+    last_processed: Instant, // last event that was let through
     debounce_duration: Duration,
 }
 impl KeyDebouncer {
+    const REPEAT_DELAY: Duration = Duration::from_millis(300);
+    const REPEAT_INTERVAL: Duration = Duration::from_millis(75);
+
+    /// The longest gap between events of a held key, covering the delay most systems
+    /// wait before the first auto repeat.
+    const HOLD_GAP: Duration = Duration::from_millis(1000);
+
     pub fn new(debounce_duration: Duration) -> Self {
+        let now = Instant::now();
         Self {
             last_key: None,
-            last_time: Instant::now(),
+            last_time: now,
+            held_since: now,
+            last_processed: now,
             debounce_duration,
         }
     }
 
-    pub fn should_process(&mut self, key: &KeyPress) -> bool {
-        let now = Instant::now();
+    pub fn should_process(&mut self, key: &KeyPress, now: Instant) -> bool {
+        let gap = now.saturating_duration_since(self.last_time);
+        let same_key = self.last_key.as_ref() == Some(key);
+        self.last_time = now;
 
-        // If it's a different key, always process it
-        if self.last_key.as_ref() != Some(key) {
+        // A different key, or the same key after a pause, starts a new press
+        if !same_key || gap >= Self::HOLD_GAP {
             self.last_key = Some(key.clone());
-            self.last_time = now;
+            self.held_since = now;
+            self.last_processed = now;
             return true;
         }
 
-        // Same key - check if enough time has passed
-        if now.duration_since(self.last_time) >= self.debounce_duration {
-            self.last_time = now;
+        // Slower than a burst - a second tap or the OS repeat, which continues the press
+        if gap >= self.debounce_duration {
+            self.last_processed = now;
+            return true;
+        }
+
+        // Burst - ignore it initially, then repeat at a steady rate
+        if now.saturating_duration_since(self.held_since) >= Self::REPEAT_DELAY
+            && now.saturating_duration_since(self.last_processed) >= Self::REPEAT_INTERVAL
+        {
+            self.last_processed = now;
             return true;
         }
 
         false
     }
 
+    /// The key was released, so pressing it again is a new press.
+    pub fn release(&mut self, key: &KeyPress) {
+        if self.last_key.as_ref() == Some(key) {
+            self.last_key = None;
+        }
+    }
+
     pub fn reset(&mut self) {
         self.last_key = None;
-        self.last_time = Instant::now();
     }
 }
 
-pub const DEBOUNCE_DURATION: Duration = Duration::from_millis(50);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEBOUNCE: Duration = Duration::from_millis(50);
+
+    fn key(c: char) -> KeyPress {
+        KeyPress {
+            code: KeyCode::Char(c),
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn burst_is_ignored_then_repeats() {
+        let mut debouncer = KeyDebouncer::new(DEBOUNCE);
+        let start = Instant::now();
+        let down = key('j');
+
+        assert!(debouncer.should_process(&down, start));
+        // Duplicates within the debounce window are dropped for the repeat delay
+        for millis in (10..300).step_by(10) {
+            assert!(!debouncer.should_process(&down, start + ms(millis)));
+        }
+        // Then let through every repeat interval
+        assert!(debouncer.should_process(&down, start + ms(300)));
+        assert!(!debouncer.should_process(&down, start + ms(340)));
+        assert!(debouncer.should_process(&down, start + ms(380)));
+    }
+
+    #[test]
+    fn held_key_does_not_stall_when_os_repeat_starts() {
+        let mut debouncer = KeyDebouncer::new(DEBOUNCE);
+        let start = Instant::now();
+        let down = key('j');
+
+        assert!(debouncer.should_process(&down, start));
+        // The first OS repeat arrives after its delay, outside the debounce window
+        assert!(debouncer.should_process(&down, start + ms(500)));
+
+        // Further repeats every 30ms go through at the repeat interval, with no new
+        // initial delay before them.
+        let processed = (1..=10)
+            .filter(|i| debouncer.should_process(&down, start + ms(500 + i * 30)))
+            .count();
+        assert_eq!(processed, 3); // at 590ms, 680ms and 770ms
+    }
+
+    #[test]
+    fn different_key_is_processed() {
+        let mut debouncer = KeyDebouncer::new(DEBOUNCE);
+        let start = Instant::now();
+
+        assert!(debouncer.should_process(&key('j'), start));
+        assert!(debouncer.should_process(&key('k'), start + ms(10)));
+        assert!(debouncer.should_process(&key('j'), start + ms(20)));
+    }
+
+    #[test]
+    fn release_starts_a_new_press() {
+        let mut debouncer = KeyDebouncer::new(DEBOUNCE);
+        let start = Instant::now();
+        let down = key('j');
+
+        assert!(debouncer.should_process(&down, start));
+        debouncer.release(&down);
+        assert!(debouncer.should_process(&down, start + ms(20)));
+
+        // Releasing a different key leaves the current press alone
+        debouncer.release(&key('k'));
+        assert!(!debouncer.should_process(&down, start + ms(30)));
+    }
+
+    #[test]
+    fn pause_starts_a_new_press() {
+        let mut debouncer = KeyDebouncer::new(DEBOUNCE);
+        let start = Instant::now();
+        let down = key('j');
+
+        assert!(debouncer.should_process(&down, start));
+        assert!(debouncer.should_process(&down, start + ms(1500)));
+        assert!(!debouncer.should_process(&down, start + ms(1510)));
+    }
+}
@@ -2,11 +2,17 @@ pub mod modals;
 pub mod notifications;
 pub mod views;
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
 use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
 
+use crate::device_status::{self, DeviceStatus, SIGNAL_BARS};
 use crate::error::AppResult;
+use crate::glyphs::glyphs;
 use crate::modals::{AppModal, ModalResponse};
 use crate::theme::Theme;
 use crate::types::AppAction;
@@ -16,12 +22,76 @@ pub trait ViewBase {
 
     async fn load(&mut self, ctx: Self::Context<'_>) -> AppResult<()>;
     async fn handle_key(&mut self, key: KeyEvent, ctx: Self::Context<'_>) -> Option<AppAction>;
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, ctx: Self::Context<'_>);
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, ctx: Self::Context<'_>);
+
+    /// The keys to show in the hint bar below the view, most relevant first.
+    fn key_hints(&self, _ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        Vec::new()
+    }
+}
+
+/// A key (or combination) and what it does, eg: `("Esc", "back")`.
+pub type KeyHint = (String, &'static str);
+
+/// Draw key hints on a single line as `(key) action`, dropping whichever don't fit the
+/// width, so views should list their most relevant keys first.
+pub fn render_key_hints(frame: &mut Frame, area: Rect, hints: &[KeyHint], theme: &Theme) {
+    let mut text = String::new();
+    for (key, action) in hints {
+        let separator = if text.is_empty() { "" } else { " | " };
+        let hint = format!("{separator}({key}) {action}");
+        if text.width() + hint.width() > area.width as usize {
+            break;
+        }
+        text.push_str(&hint);
+    }
+
+    let hints = Paragraph::new(text)
+        .style(Style::default().fg(theme.text_muted))
+        .centered();
+    frame.render_widget(hints, area);
+}
+
+/// The modem's signal bars and battery charge on one line for the status bar, with
+/// placeholders while the status is unknown or either reading is unavailable.
+pub fn device_status_line(status: Option<&DeviceStatus>, theme: &Theme) -> Line<'static> {
+    let muted = Style::default().fg(theme.text_muted);
+    let mut spans = vec![Span::styled(glyphs().signal, muted)];
+
+    match status.and_then(|status| status.signal) {
+        Some((bars, color)) => {
+            let filled = bars.min(SIGNAL_BARS);
+            spans.push(Span::styled(glyphs().block.repeat(filled), color));
+            spans.push(Span::styled(
+                glyphs().block.repeat(SIGNAL_BARS - filled),
+                muted,
+            ));
+        }
+        None => spans.push(Span::styled("-".repeat(SIGNAL_BARS), muted)),
+    }
+
+    spans.push(Span::raw(" "));
+    spans.push(Span::styled(glyphs().battery, muted));
+    match status.and_then(|status| status.battery) {
+        Some((percentage, charging)) => {
+            if charging {
+                spans.push(Span::styled(glyphs().charging, muted));
+            }
+            spans.push(Span::styled(
+                format!("{percentage}%"),
+                device_status::battery_color(percentage),
+            ));
+        }
+        None => spans.push(Span::styled("--%", muted)),
+    }
+
+    Line::from(spans)
 }
 
 pub trait ModalResponderComponent {
-    /// Handle a modal response with its associated metadata.
-    /// Returns a `KeyResponse` if the app state should change.
+    /// Handle a response from a modal this component opened. The modal carries both its
+    /// `id` and metadata, so match on the `id` to tell apart modals sharing metadata.
+    /// Returns an `AppAction` if the app state should change.
     fn handle_modal_response(
         &mut self,
         modal: &mut AppModal,
@@ -29,6 +99,23 @@ pub trait ModalResponderComponent {
     ) -> Option<AppAction>;
 }
 
+/// Map the vim home-row keys (lowercase h/j/k/l, without modifiers) onto the arrow keys,
+/// for views that navigate lists. Anything else is returned unchanged.
+pub fn vim_navigation_key(key: KeyEvent) -> KeyEvent {
+    if !key.modifiers.is_empty() {
+        return key;
+    }
+
+    let code = match key.code {
+        KeyCode::Char('h') => KeyCode::Left,
+        KeyCode::Char('j') => KeyCode::Down,
+        KeyCode::Char('k') => KeyCode::Up,
+        KeyCode::Char('l') => KeyCode::Right,
+        _ => return key,
+    };
+    KeyEvent { code, ..key }
+}
+
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::vertical([
         Constraint::Percentage((100 - percent_y) / 2),
@@ -0,0 +1,76 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::prelude::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
+
+use crate::modals::ModalResponse;
+use crate::theme::Theme;
+use crate::ui::modals::{ModalComponent, ModalUtils};
+
+/// Diagnostic summary of the app and its connection, useful for bug reports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AboutModal {
+    pub fields: Vec<(&'static str, String)>, // label, value
+}
+impl AboutModal {
+    pub fn new(fields: Vec<(&'static str, String)>) -> Self {
+        Self { fields }
+    }
+}
+impl ModalComponent for AboutModal {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
+        match key.code {
+            KeyCode::Esc => Some(ModalResponse::Dismissed),
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        ModalUtils::render_base(
+            frame,
+            "About",
+            |frame, area, theme| {
+                let layout = Layout::vertical([
+                    Constraint::Length(1), // Top padding
+                    Constraint::Min(1),    // Fields
+                    Constraint::Length(1), // Help text
+                ])
+                .split(area);
+
+                let label_width = self
+                    .fields
+                    .iter()
+                    .map(|(label, _)| label.width())
+                    .max()
+                    .unwrap_or(0);
+                let lines: Vec<Line> = self
+                    .fields
+                    .iter()
+                    .map(|(label, value)| {
+                        Line::from(vec![
+                            Span::styled(
+                                format!(
+                                    " {label}{}  ",
+                                    " ".repeat(label_width.saturating_sub(label.width()))
+                                ),
+                                theme.secondary_style,
+                            ),
+                            Span::styled(value.as_str(), theme.primary_style),
+                        ])
+                    })
+                    .collect();
+                frame.render_widget(Paragraph::new(lines), layout[1]);
+
+                let help = Paragraph::new("(Esc) close")
+                    .style(theme.secondary_style)
+                    .alignment(Alignment::Center);
+                frame.render_widget(help, layout[2]);
+            },
+            theme,
+            60,
+            40,
+        );
+    }
+}
@@ -1,8 +1,9 @@
-use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::layout::{Alignment, Constraint, Layout};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::widgets::{Paragraph, Wrap};
 use ratatui::Frame;
 
+use crate::glyphs::glyphs;
 use crate::modals::ModalResponse;
 use crate::theme::Theme;
 use crate::ui::modals::{
@@ -14,12 +15,14 @@ use crate::ui::modals::{
 pub struct ConfirmationModal {
     pub message: String,
     pub selected_yes: bool,
+    button_areas: Vec<Rect>,
 }
 impl ConfirmationModal {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
             selected_yes: false,
+            button_areas: Vec::new(),
         }
     }
 }
@@ -40,6 +43,17 @@ impl ModalComponent for ConfirmationModal {
         }
     }
 
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<ModalResponse> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+
+        // Clicking a button selects and activates it.
+        let index = ModalUtils::clicked_button(&self.button_areas, mouse)?;
+        self.selected_yes = index == 0;
+        self.handle_key(KeyEvent::from(KeyCode::Enter))
+    }
+
     fn render(&mut self, frame: &mut Frame, theme: &Theme) {
         let button_styles = ModalButtonComponentStyles::from_theme(theme);
         let styled_buttons = ModalButtonComponent::create_yes_no_buttons(&button_styles);
@@ -65,12 +79,16 @@ impl ModalComponent for ConfirmationModal {
 
                 // Buttons
                 let selected_index = usize::from(!self.selected_yes);
-                ModalUtils::render_buttons(frame, layout[2], &styled_buttons, selected_index);
+                self.button_areas =
+                    ModalUtils::render_buttons(frame, layout[2], &styled_buttons, selected_index);
 
                 // Help text
-                let help = Paragraph::new("(←/→) select | (Enter) confirm | (Esc) cancel")
-                    .style(theme.secondary_style)
-                    .alignment(Alignment::Center);
+                let help = Paragraph::new(format!(
+                    "({}) select | (Enter) confirm | (Esc) cancel",
+                    glyphs().keys_left_right
+                ))
+                .style(theme.secondary_style)
+                .alignment(Alignment::Center);
                 frame.render_widget(help, layout[3]);
             },
             theme,
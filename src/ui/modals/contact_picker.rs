@@ -0,0 +1,213 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::prelude::{Line, Modifier, Span, Style};
+use ratatui::style::Color;
+use ratatui::widgets::{Block, List, ListItem, Paragraph};
+use ratatui::Frame;
+
+use crate::contacts::Contact;
+use crate::glyphs::glyphs;
+use crate::modals::{AppModal, ModalResponse};
+use crate::sanitize::sanitize_text;
+use crate::theme::Theme;
+use crate::types::{AppAction, ContentNewlines};
+use crate::ui::modals::loading::LoadingModal;
+use crate::ui::modals::{ModalComponent, ModalLoadBehaviour, ModalUtils};
+use crate::ui::views::ViewStateRequest;
+
+/// Go-to-number input, which suggests known contacts matching the typed text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactPickerModal {
+    contacts: Option<Vec<Contact>>,
+    input_buffer: String,
+    selected_suggestion: Option<usize>,
+}
+impl ContactPickerModal {
+    const MAX_SUGGESTIONS: usize = 8;
+
+    /// Create with the cached contacts, which are loaded once set active if None.
+    pub fn new(contacts: Option<Vec<Contact>>) -> Self {
+        Self {
+            contacts,
+            input_buffer: String::new(),
+            selected_suggestion: None,
+        }
+    }
+
+    fn suggestions(&self) -> Vec<&Contact> {
+        self.contacts
+            .iter()
+            .flatten()
+            .filter(|contact| contact.matches(self.input_buffer.trim()))
+            .take(Self::MAX_SUGGESTIONS)
+            .collect()
+    }
+
+    fn select_next(&mut self) {
+        let count = self.suggestions().len();
+        if count == 0 {
+            return;
+        }
+        self.selected_suggestion = Some(self.selected_suggestion.map_or(0, |i| (i + 1) % count));
+    }
+
+    fn select_previous(&mut self) {
+        let count = self.suggestions().len();
+        if count == 0 {
+            return;
+        }
+        self.selected_suggestion = match self.selected_suggestion {
+            None | Some(0) => Some(count - 1),
+            Some(i) => Some(i - 1),
+        };
+    }
+}
+impl ModalComponent for ContactPickerModal {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
+        match key.code {
+            KeyCode::Esc => return Some(ModalResponse::Dismissed),
+            KeyCode::Enter => {
+                // Prefer the highlighted suggestion over the typed text.
+                let number = self
+                    .selected_suggestion
+                    .and_then(|i| self.suggestions().get(i).map(|c| c.number.clone()))
+                    .unwrap_or_else(|| self.input_buffer.trim().to_string());
+
+                if !number.is_empty() {
+                    return Some(ModalResponse::TextInput(Some(number)));
+                }
+            }
+            KeyCode::Down | KeyCode::Tab => self.select_next(),
+            KeyCode::Up | KeyCode::BackTab => self.select_previous(),
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+                self.selected_suggestion = None;
+            }
+            KeyCode::Char(c) if !c.is_control() => {
+                self.input_buffer.push(c);
+                self.selected_suggestion = None;
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        ModalUtils::render_base(
+            frame,
+            "Go To Number",
+            |frame, area, theme| {
+                let layout = Layout::vertical([
+                    Constraint::Length(1), // Prompt
+                    Constraint::Length(3), // Input box
+                    Constraint::Min(1),    // Suggestions
+                    Constraint::Length(1), // Help text
+                ])
+                .split(area);
+
+                let prompt =
+                    Paragraph::new("Phone number or contact name:").style(theme.secondary_style);
+                frame.render_widget(prompt, layout[0]);
+
+                let input_text = if self.input_buffer.is_empty() {
+                    Line::from(vec![
+                        Span::styled("+1234567890", Style::default().fg(theme.text_muted)),
+                        Span::styled(
+                            glyphs().cursor,
+                            Style::default()
+                                .fg(theme.input_cursor)
+                                .add_modifier(Modifier::SLOW_BLINK),
+                        ),
+                    ])
+                } else {
+                    Line::from(vec![
+                        Span::raw(self.input_buffer.clone()),
+                        Span::styled(
+                            glyphs().cursor,
+                            Style::default()
+                                .fg(theme.input_cursor)
+                                .add_modifier(Modifier::SLOW_BLINK),
+                        ),
+                    ])
+                };
+                let input = Paragraph::new(input_text)
+                    .style(theme.input_style)
+                    .block(Block::bordered().border_style(theme.border_focused_style));
+                frame.render_widget(input, layout[1]);
+
+                // Suggestions
+                let suggestions = self.suggestions();
+                let items: Vec<ListItem> = if self.contacts.is_none() {
+                    vec![ListItem::new("Loading contacts...").style(theme.secondary_style)]
+                } else if suggestions.is_empty() {
+                    vec![ListItem::new("No matching contacts").style(theme.secondary_style)]
+                } else {
+                    suggestions
+                        .iter()
+                        .enumerate()
+                        .map(|(i, contact)| {
+                            // Sanitized, as names and numbers are set by other clients too.
+                            let number = sanitize_text(&contact.number, ContentNewlines::Strip);
+                            let content = match &contact.friendly_name {
+                                Some(name) => {
+                                    let name = sanitize_text(name, ContentNewlines::Strip);
+                                    format!("{number} {} {name}", glyphs().separator)
+                                }
+                                None => number,
+                            };
+                            let style = if Some(i) == self.selected_suggestion {
+                                Style::default().bg(theme.text_accent).fg(Color::Black)
+                            } else {
+                                Style::default().fg(theme.text_muted)
+                            };
+                            ListItem::new(content).style(style)
+                        })
+                        .collect()
+                };
+                frame.render_widget(List::new(items), layout[2]);
+
+                let help = Paragraph::new(format!(
+                    "({}) pick contact | (Enter) go | (Esc) cancel",
+                    glyphs().keys_up_down
+                ))
+                .style(theme.secondary_style)
+                .alignment(Alignment::Center);
+                frame.render_widget(help, layout[3]);
+            },
+            theme,
+            40,
+            45,
+        );
+    }
+
+    fn load(&self) -> ModalLoadBehaviour {
+        if self.contacts.is_some() {
+            return ModalLoadBehaviour::None;
+        }
+
+        ModalLoadBehaviour::Function(Box::new(move |ctx| {
+            tokio::spawn(async move {
+                let contacts = match ctx.contacts.fetch(&ctx.http).await {
+                    Ok(contacts) => contacts,
+                    Err(e) => {
+                        let _ = ctx.sender.send(AppAction::SetViewState {
+                            state: ViewStateRequest::from(e),
+                            dismiss_modal: true,
+                        });
+                        return;
+                    }
+                };
+
+                let modal = AppModal::new("go_to_number", ContactPickerModal::new(Some(contacts)));
+                let _ = ctx.sender.send(AppAction::SetModal(Some(modal)));
+            });
+
+            let modal = AppModal::new(
+                "go_to_number_loading",
+                LoadingModal::new("Loading contacts..."),
+            );
+            (Some(AppAction::SetModal(Some(modal))), true)
+        }))
+    }
+}
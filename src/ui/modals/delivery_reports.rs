@@ -1,11 +1,13 @@
 use crate::error::AppError;
+use crate::glyphs::glyphs;
 use crate::modals::{AppModal, ModalResponse};
 use crate::theme::Theme;
+use crate::timestamps::{DisplayTimezone, TimestampFormatter};
 use crate::types::AppAction;
 use crate::ui::modals::loading::LoadingModal;
 use crate::ui::modals::{ModalComponent, ModalLoadBehaviour, ModalUtils};
 use crate::ui::views::ViewStateRequest;
-use chrono::{DateTime, Local, TimeZone};
+use chrono::{DateTime, Utc};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Alignment, Constraint, Layout};
 use ratatui::prelude::{Line, Modifier, Span, Style};
@@ -16,10 +18,73 @@ use sms_client::types::http::HttpPaginationOptions;
 use sms_client::types::sms::{SmsDeliveryReport, SmsDeliveryReportStatusCategory, SmsMessage};
 use std::time::SystemTime;
 
+/// The glyph for a delivery status. The compact glyphs used in the messages table only
+/// tell delivered and undelivered apart, with sent and retrying both still pending.
+fn status_icon(category: &SmsDeliveryReportStatusCategory, compact: bool) -> &'static str {
+    let glyphs = glyphs();
+    match (category, compact) {
+        (SmsDeliveryReportStatusCategory::Sent, false) => glyphs.report_sent,
+        (SmsDeliveryReportStatusCategory::Received, false) => glyphs.report_received,
+        (SmsDeliveryReportStatusCategory::Retrying, false) => glyphs.report_retrying,
+        (SmsDeliveryReportStatusCategory::Failed, false) => glyphs.report_failed,
+        (SmsDeliveryReportStatusCategory::Received, true) => glyphs.delivered,
+        (SmsDeliveryReportStatusCategory::Failed, true) => glyphs.undelivered,
+        (
+            SmsDeliveryReportStatusCategory::Sent | SmsDeliveryReportStatusCategory::Retrying,
+            true,
+        ) => glyphs.pending,
+    }
+}
+
+/// Is a delivery status final, rather than still waiting on the network.
+fn is_final(category: &SmsDeliveryReportStatusCategory) -> bool {
+    matches!(
+        category,
+        SmsDeliveryReportStatusCategory::Received | SmsDeliveryReportStatusCategory::Failed
+    )
+}
+
+/// Style for a delivery status, shared by the reports timeline and the messages table.
+pub fn status_style(category: &SmsDeliveryReportStatusCategory, theme: &Theme) -> Style {
+    match category {
+        SmsDeliveryReportStatusCategory::Sent => Style::default().fg(theme.text_accent),
+        SmsDeliveryReportStatusCategory::Received => Style::default()
+            .fg(theme.text_accent)
+            .add_modifier(Modifier::BOLD),
+        SmsDeliveryReportStatusCategory::Retrying => Style::default().fg(theme.text_muted),
+        SmsDeliveryReportStatusCategory::Failed => theme.error_style.add_modifier(Modifier::BOLD),
+    }
+}
+
+/// Compact glyph and style for a message's latest delivery status, where None
+/// (no reports yet), sent and retrying are all shown as pending.
+pub fn status_indicator(
+    category: Option<&SmsDeliveryReportStatusCategory>,
+    theme: &Theme,
+) -> (&'static str, Style) {
+    match category {
+        Some(category) if is_final(category) => {
+            (status_icon(category, true), status_style(category, theme))
+        }
+        _ => (glyphs().pending, Style::default().fg(theme.text_muted)),
+    }
+}
+
+/// Time taken between two reports, eg: "+2.3s", "+4m05s" or "+1h20m".
+fn format_delta(delta: chrono::TimeDelta) -> String {
+    let millis = delta.num_milliseconds().max(0);
+    let seconds = millis / 1000;
+    match seconds {
+        0..=59 => format!("+{}.{}s", seconds, (millis % 1000) / 100),
+        60..=3599 => format!("+{}m{:02}s", seconds / 60, seconds % 60),
+        _ => format!("+{}h{:02}m", seconds / 3600, (seconds % 3600) / 60),
+    }
+}
+
 /// A delivery report entry with pre-computed fields for efficient rendering.
 #[derive(Debug, Clone, PartialEq)]
 struct ReportEntry {
-    timestamp: Option<DateTime<Local>>,
+    timestamp: Option<DateTime<Utc>>,
     status_category: SmsDeliveryReportStatusCategory,
 }
 impl ReportEntry {
@@ -34,46 +99,42 @@ impl ReportEntry {
     /// Create an entry from an API delivery report.
     fn from_api(report: &SmsDeliveryReport) -> Self {
         Self {
-            timestamp: report
-                .created_at
-                .and_then(|ts| Local.timestamp_opt(i64::from(ts), 0).single()),
+            timestamp: report.created_at.and_then(DisplayTimezone::from_unix),
             status_category: SmsDeliveryReportStatusCategory::from(report.status),
         }
     }
 
-    fn icon(&self) -> &'static str {
-        match self.status_category {
-            SmsDeliveryReportStatusCategory::Sent => "📤",
-            SmsDeliveryReportStatusCategory::Received => "✅",
-            SmsDeliveryReportStatusCategory::Retrying => "🔄",
-            SmsDeliveryReportStatusCategory::Failed => "❌",
+    /// Time since the previous (older) entry, "--" if either has no timestamp.
+    fn delta_from(&self, previous: &Self) -> String {
+        match (self.timestamp, previous.timestamp) {
+            (Some(time), Some(previous)) => format_delta(time - previous),
+            _ => "--".to_string(),
         }
     }
 
-    fn style(&self, theme: &Theme) -> Style {
-        match self.status_category {
-            SmsDeliveryReportStatusCategory::Sent => Style::default().fg(theme.text_accent),
-            SmsDeliveryReportStatusCategory::Received => Style::default()
-                .fg(theme.text_accent)
-                .add_modifier(Modifier::BOLD),
-            SmsDeliveryReportStatusCategory::Retrying => Style::default().fg(theme.text_muted),
-            SmsDeliveryReportStatusCategory::Failed => {
-                theme.error_style.add_modifier(Modifier::BOLD)
-            }
-        }
-    }
-
-    fn to_timeline_entry(&self, theme: &Theme) -> Line<'static> {
+    fn to_timeline_entry(
+        &self,
+        delta: Option<String>,
+        theme: &Theme,
+        timestamps: &TimestampFormatter,
+    ) -> Line<'static> {
         let time_str = self
             .timestamp
-            .map(|dt| dt.format("%H:%M:%S").to_string())
+            .map(|dt| timestamps.precise_time(dt))
             .unwrap_or_else(|| "--:--:--".to_string());
 
-        let style = self.style(theme);
+        let style = status_style(&self.status_category, theme);
 
         Line::from(vec![
-            Span::styled(format!("{} ", self.icon()), style),
+            Span::styled(
+                format!("{} ", status_icon(&self.status_category, false)),
+                style,
+            ),
             Span::styled(format!("{time_str} "), theme.secondary_style),
+            Span::styled(
+                format!("{:<8}", delta.unwrap_or_default()),
+                Style::default().fg(theme.text_muted),
+            ),
             Span::styled(self.status_category.to_string(), style),
         ])
     }
@@ -83,21 +144,30 @@ impl ReportEntry {
 pub struct DeliveryReportsModal {
     message: SmsMessage,
     reports: Option<Vec<ReportEntry>>,
+    awaiting_reports: bool, // Loaded, but the server had none
+    timestamps: TimestampFormatter,
 }
 impl DeliveryReportsModal {
     pub const MAX_REPORTS_USIZE: usize = 10;
     pub const MAX_REPORTS_U16: u16 = 10;
 
     /// Create uninitialized modal, which will trigger it to load once set active.
-    pub fn new(message: SmsMessage) -> Self {
+    pub fn new(message: SmsMessage, timestamps: TimestampFormatter) -> Self {
         Self {
             message,
             reports: None,
+            awaiting_reports: false,
+            timestamps,
         }
     }
 
     /// Create an initialized modal with a set of delivery reports.
-    pub fn with_reports(message: SmsMessage, api_reports: Vec<SmsDeliveryReport>) -> Self {
+    pub fn with_reports(
+        message: SmsMessage,
+        api_reports: Vec<SmsDeliveryReport>,
+        timestamps: TimestampFormatter,
+    ) -> Self {
+        let awaiting_reports = api_reports.is_empty();
         let mut reports: Vec<ReportEntry> = api_reports.iter().map(ReportEntry::from_api).collect();
 
         // Add synthetic "sent" report if available
@@ -116,6 +186,8 @@ impl DeliveryReportsModal {
         Self {
             message,
             reports: Some(reports),
+            awaiting_reports,
+            timestamps,
         }
     }
 
@@ -124,14 +196,28 @@ impl DeliveryReportsModal {
             .reports
             .as_ref()
             .map(|reports| {
+                // Newest first, so each entry's delta is from the one after it.
                 reports
                     .iter()
+                    .enumerate()
                     .take(Self::MAX_REPORTS_USIZE)
-                    .map(|r| r.to_timeline_entry(theme))
+                    .map(|(i, r)| {
+                        let delta = reports.get(i + 1).map(|previous| r.delta_from(previous));
+                        r.to_timeline_entry(delta, theme, &self.timestamps)
+                    })
                     .collect()
             })
             .unwrap_or_else(|| vec![Line::raw("Loading...")]);
 
+        // Only the synthetic sent entry, so say why rather than leaving a bare timeline.
+        if self.awaiting_reports {
+            lines.push(Line::raw(""));
+            let style = Style::default().fg(theme.text_muted);
+            lines.push(Line::styled("No delivery reports yet,", style));
+            lines.push(Line::styled("awaiting carrier confirmation.", style));
+            return lines;
+        }
+
         // Pad to consistent height
         lines.resize_with(Self::MAX_REPORTS_USIZE, || Line::raw(""));
         lines.push(Line::raw(""));
@@ -142,6 +228,12 @@ impl ModalComponent for DeliveryReportsModal {
     fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
         match key.code {
             KeyCode::Esc => Some(ModalResponse::Dismissed),
+            KeyCode::Char('r' | 'R') => {
+                // Clearing the reports makes the loader fetch them again. Once cleared the
+                // loading modal takes over, so a refresh can't start while one is running.
+                self.reports.take()?;
+                Some(ModalResponse::Reload)
+            }
             _ => None,
         }
     }
@@ -163,7 +255,7 @@ impl ModalComponent for DeliveryReportsModal {
                     Paragraph::new(self.render_timeline(theme)).alignment(Alignment::Left);
                 frame.render_widget(timeline_paragraph, sections[1]);
 
-                let help = Paragraph::new("(Esc) close")
+                let help = Paragraph::new("(r) refresh | (Esc) close")
                     .style(theme.primary_style)
                     .alignment(Alignment::Center);
                 frame.render_widget(help, sections[2]);
@@ -180,12 +272,13 @@ impl ModalComponent for DeliveryReportsModal {
         }
 
         let message = self.message.clone();
+        let timestamps = self.timestamps.clone();
         ModalLoadBehaviour::Function(Box::new(move |ctx| {
             tokio::spawn(async move {
                 let pagination =
                     HttpPaginationOptions::default().with_limit(Self::MAX_REPORTS_USIZE as u64);
                 let reports = match ctx
-                    .0
+                    .http
                     .get_delivery_reports(
                         message
                             .message_id
@@ -196,7 +289,7 @@ impl ModalComponent for DeliveryReportsModal {
                 {
                     Ok(reports) => reports,
                     Err(e) => {
-                        let _ = ctx.1.send(AppAction::SetViewState {
+                        let _ = ctx.sender.send(AppAction::SetViewState {
                             state: ViewStateRequest::from(AppError::from(ClientError::from(e))),
                             dismiss_modal: true,
                         });
@@ -206,9 +299,9 @@ impl ModalComponent for DeliveryReportsModal {
 
                 let modal = AppModal::new(
                     "delivery_reports",
-                    DeliveryReportsModal::with_reports(message, reports),
+                    DeliveryReportsModal::with_reports(message, reports, timestamps),
                 );
-                let _ = ctx.1.send(AppAction::SetModal(Some(modal)));
+                let _ = ctx.sender.send(AppAction::SetModal(Some(modal)));
             });
 
             let modal = AppModal::new(
@@ -219,3 +312,30 @@ impl ModalComponent for DeliveryReportsModal {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATEGORIES: [SmsDeliveryReportStatusCategory; 4] = [
+        SmsDeliveryReportStatusCategory::Sent,
+        SmsDeliveryReportStatusCategory::Received,
+        SmsDeliveryReportStatusCategory::Retrying,
+        SmsDeliveryReportStatusCategory::Failed,
+    ];
+
+    #[test]
+    fn indicator_matches_compact_icon() {
+        let theme = Theme::new(&ratatui::style::palette::tailwind::EMERALD);
+        for category in &CATEGORIES {
+            let (glyph, _) = status_indicator(Some(category), &theme);
+            assert_eq!(glyph, status_icon(category, true), "{category:?}");
+            assert_eq!(
+                glyph == glyphs().pending,
+                !is_final(category),
+                "{category:?}"
+            );
+        }
+        assert_eq!(status_indicator(None, &theme).0, glyphs().pending);
+    }
+}
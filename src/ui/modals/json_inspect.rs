@@ -0,0 +1,88 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::widgets::{Paragraph, Wrap};
+use ratatui::Frame;
+use serde::Serialize;
+
+use crate::glyphs::glyphs;
+use crate::modals::ModalResponse;
+use crate::theme::Theme;
+use crate::ui::modals::{ModalComponent, ModalUtils};
+
+/// Read-only, scrollable view of some value as pretty JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonInspectModal {
+    pub title: String,
+    pub content: String,
+    pub scroll: u16,
+}
+impl JsonInspectModal {
+    const PAGE_SCROLL: u16 = 10;
+
+    pub fn new<T: Serialize>(title: impl Into<String>, value: &T) -> Self {
+        let content = serde_json::to_string_pretty(value)
+            .unwrap_or_else(|e| format!("Failed to serialize value: {e}"));
+
+        Self {
+            title: title.into(),
+            content,
+            scroll: 0,
+        }
+    }
+
+    fn max_scroll(&self) -> u16 {
+        u16::try_from(self.content.lines().count().saturating_sub(1)).unwrap_or(u16::MAX)
+    }
+}
+impl ModalComponent for JsonInspectModal {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
+        match key.code {
+            KeyCode::Esc => return Some(ModalResponse::Dismissed),
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = (self.scroll + 1).min(self.max_scroll()),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(Self::PAGE_SCROLL),
+            KeyCode::PageDown => {
+                self.scroll = self
+                    .scroll
+                    .saturating_add(Self::PAGE_SCROLL)
+                    .min(self.max_scroll());
+            }
+            KeyCode::Home => self.scroll = 0,
+            KeyCode::End => self.scroll = self.max_scroll(),
+            _ => {}
+        }
+
+        None
+    }
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        ModalUtils::render_base(
+            frame,
+            &self.title,
+            |frame, area, theme| {
+                let layout = Layout::vertical([
+                    Constraint::Min(1),    // JSON content
+                    Constraint::Length(1), // Help text
+                ])
+                .split(area);
+
+                let content = Paragraph::new(self.content.as_str())
+                    .style(theme.primary_style)
+                    .wrap(Wrap { trim: false })
+                    .scroll((self.scroll, 0));
+                frame.render_widget(content, layout[0]);
+
+                let help = Paragraph::new(format!(
+                    "({}/PgUp/PgDn) scroll | (Esc) close",
+                    glyphs().keys_up_down
+                ))
+                .style(theme.secondary_style)
+                .alignment(Alignment::Center);
+                frame.render_widget(help, layout[1]);
+            },
+            theme,
+            70,
+            80,
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::prelude::Style;
+use ratatui::style::Color;
+use ratatui::widgets::{List, ListItem, Paragraph};
+use ratatui::Frame;
+
+use crate::glyphs::glyphs;
+use crate::modals::ModalResponse;
+use crate::theme::Theme;
+use crate::ui::modals::{ModalComponent, ModalUtils};
+
+/// Pick one of a list of strings, responding with the chosen item as `TextInput`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSelectModal {
+    pub title: String,
+    pub items: Vec<String>,
+    pub selected: usize,
+}
+impl ListSelectModal {
+    pub fn new(title: impl Into<String>, items: Vec<String>) -> Self {
+        Self {
+            title: title.into(),
+            items,
+            selected: 0,
+        }
+    }
+}
+impl ModalComponent for ListSelectModal {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
+        match key.code {
+            KeyCode::Esc => return Some(ModalResponse::Dismissed),
+            KeyCode::Enter => {
+                let item = self.items.get(self.selected)?;
+                return Some(ModalResponse::TextInput(Some(item.clone())));
+            }
+            KeyCode::Down | KeyCode::Tab if !self.items.is_empty() => {
+                self.selected = (self.selected + 1) % self.items.len();
+            }
+            KeyCode::Up | KeyCode::BackTab if !self.items.is_empty() => {
+                self.selected = self.selected.checked_sub(1).unwrap_or(self.items.len() - 1);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        ModalUtils::render_base(
+            frame,
+            &self.title,
+            |frame, area, theme| {
+                let layout = Layout::vertical([
+                    Constraint::Length(1), // Top padding
+                    Constraint::Min(1),    // Items
+                    Constraint::Length(1), // Help text
+                ])
+                .split(area);
+
+                let items: Vec<ListItem> = self
+                    .items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        let style = if i == self.selected {
+                            Style::default().bg(theme.text_accent).fg(Color::Black)
+                        } else {
+                            Style::default().fg(theme.text_muted)
+                        };
+                        ListItem::new(format!(" {item}")).style(style)
+                    })
+                    .collect();
+                frame.render_widget(List::new(items), layout[1]);
+
+                let help = Paragraph::new(format!(
+                    "({}) select | (Enter) choose | (Esc) cancel",
+                    glyphs().keys_up_down
+                ))
+                .style(theme.secondary_style)
+                .alignment(Alignment::Center);
+                frame.render_widget(help, layout[2]);
+            },
+            theme,
+            60,
+            40,
+        );
+    }
+}
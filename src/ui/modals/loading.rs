@@ -4,6 +4,7 @@ use ratatui::prelude::{Modifier, Style};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
+use crate::glyphs::glyphs;
 use crate::modals::ModalResponse;
 use crate::theme::Theme;
 use crate::ui::modals::{ModalComponent, ModalUtils};
@@ -22,7 +23,7 @@ impl LoadingModal {
     }
 
     fn get_spinner_char(&self) -> char {
-        let spinner_chars = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+        let spinner_chars = glyphs().spinner;
         let index = self.frame_count % spinner_chars.len();
         spinner_chars[index]
     }
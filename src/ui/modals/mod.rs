@@ -1,16 +1,22 @@
-use crossterm::event::KeyEvent;
-use ratatui::layout::{Alignment, Rect};
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::layout::{Alignment, Position, Rect};
 use ratatui::prelude::{Line, Modifier, Span, Style};
 use ratatui::widgets::{Block, BorderType, Clear, Paragraph};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
 
 use crate::modals::{ModalLoadBehaviour, ModalResponse};
 use crate::theme::Theme;
 use crate::ui::centered_rect;
 
+pub mod about;
 pub mod confirmation;
+pub mod contact_picker;
 pub mod delivery_reports;
+pub mod json_inspect;
+pub mod list_select;
 pub mod loading;
+pub mod recent_errors;
 pub mod text_input;
 
 pub trait ModalComponent: std::fmt::Debug + Send + Sync {
@@ -19,6 +25,11 @@ pub trait ModalComponent: std::fmt::Debug + Send + Sync {
     /// the input is entirely ignored (by both the Modal and active View).
     fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse>;
 
+    /// Handle a mouse event, such as clicking a button. Ignored by default.
+    fn handle_mouse(&mut self, _mouse: MouseEvent) -> Option<ModalResponse> {
+        None
+    }
+
     /// Render the modal, called per frame.
     fn render(&mut self, frame: &mut Frame, theme: &Theme);
 
@@ -63,13 +74,13 @@ impl ModalUtils {
         content(frame, inner, theme);
     }
 
-    /// Render modal buttons with a selection index.
+    /// Render modal buttons with a selection index, returning each button's area for mouse hit-testing.
     fn render_buttons(
         frame: &mut Frame,
         area: Rect,
         buttons: &[ModalButtonComponent],
         selected_index: usize,
-    ) {
+    ) -> Vec<Rect> {
         let mut button_spans = Vec::new();
 
         button_spans.push(Span::raw("    "));
@@ -84,8 +95,29 @@ impl ModalUtils {
         button_spans.push(Span::raw("    "));
 
         let buttons_line = Line::from(button_spans);
+
+        // Walk the centered line to find where each button label was drawn.
+        let line_width = u16::try_from(buttons_line.width()).unwrap_or(u16::MAX);
+        let mut x = area.x + area.width.saturating_sub(line_width) / 2 + 4;
+        let button_areas = buttons
+            .iter()
+            .map(|button| {
+                let width = u16::try_from(button.label.width()).unwrap_or(0) + 4;
+                let button_area = Rect::new(x, area.y, width, 1).intersection(area);
+                x = x.saturating_add(width + 5);
+                button_area
+            })
+            .collect();
+
         let buttons_paragraph = Paragraph::new(buttons_line).alignment(Alignment::Center);
         frame.render_widget(buttons_paragraph, area);
+        button_areas
+    }
+
+    /// Find which button (if any) contains a mouse position.
+    fn clicked_button(button_areas: &[Rect], mouse: MouseEvent) -> Option<usize> {
+        let position = Position::new(mouse.column, mouse.row);
+        button_areas.iter().position(|area| area.contains(position))
     }
 }
 
@@ -162,12 +194,12 @@ impl ModalButtonComponentStyles {
             primary_normal: theme.secondary_style,
             primary_focused: Style::default()
                 .fg(theme.bg)
-                .bg(theme.text_accent)
+                .bg(theme.action_primary)
                 .add_modifier(Modifier::BOLD),
             secondary_normal: theme.secondary_style,
             secondary_focused: Style::default()
                 .fg(theme.bg)
-                .bg(theme.text_error)
+                .bg(theme.action_danger)
                 .add_modifier(Modifier::BOLD),
         }
     }
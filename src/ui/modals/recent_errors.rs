@@ -0,0 +1,96 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::prelude::{Line, Span, Style};
+use ratatui::widgets::{Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::glyphs::glyphs;
+use crate::modals::ModalResponse;
+use crate::theme::Theme;
+use crate::ui::modals::{ModalComponent, ModalUtils};
+
+/// Read-only, scrollable list of recent errors (newest first), for the debug tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentErrorsModal {
+    pub errors: Vec<(String, String)>, // Formatted time, message
+    pub scroll: u16,
+}
+impl RecentErrorsModal {
+    const PAGE_SCROLL: u16 = 10;
+
+    pub fn new(errors: Vec<(String, String)>) -> Self {
+        Self { errors, scroll: 0 }
+    }
+
+    fn max_scroll(&self) -> u16 {
+        u16::try_from(self.errors.len().saturating_sub(1)).unwrap_or(u16::MAX)
+    }
+}
+impl ModalComponent for RecentErrorsModal {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
+        match key.code {
+            KeyCode::Esc => return Some(ModalResponse::Dismissed),
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = (self.scroll + 1).min(self.max_scroll()),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(Self::PAGE_SCROLL),
+            KeyCode::PageDown => {
+                self.scroll = self
+                    .scroll
+                    .saturating_add(Self::PAGE_SCROLL)
+                    .min(self.max_scroll());
+            }
+            KeyCode::Home => self.scroll = 0,
+            KeyCode::End => self.scroll = self.max_scroll(),
+            _ => {}
+        }
+
+        None
+    }
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        ModalUtils::render_base(
+            frame,
+            "Recent Errors",
+            |frame, area, theme| {
+                let layout = Layout::vertical([
+                    Constraint::Min(1),    // Errors
+                    Constraint::Length(1), // Help text
+                ])
+                .split(area);
+
+                let lines: Vec<Line> = if self.errors.is_empty() {
+                    vec![Line::styled(
+                        "No errors since starting.",
+                        Style::default().fg(theme.text_muted),
+                    )]
+                } else {
+                    self.errors
+                        .iter()
+                        .map(|(time, message)| {
+                            Line::from(vec![
+                                Span::styled(format!("{time} "), theme.secondary_style),
+                                Span::styled(message.replace('\n', " "), theme.error_style),
+                            ])
+                        })
+                        .collect()
+                };
+
+                let content = Paragraph::new(lines)
+                    .wrap(Wrap { trim: false })
+                    .scroll((self.scroll, 0));
+                frame.render_widget(content, layout[0]);
+
+                let help = Paragraph::new(format!(
+                    "({}/PgUp/PgDn) scroll | (Esc) close",
+                    glyphs().keys_up_down
+                ))
+                .style(theme.secondary_style)
+                .alignment(Alignment::Center);
+                frame.render_widget(help, layout[1]);
+            },
+            theme,
+            70,
+            80,
+        );
+    }
+}
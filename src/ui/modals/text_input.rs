@@ -1,10 +1,11 @@
+use crate::glyphs::glyphs;
 use crate::modals::ModalResponse;
 use crate::theme::Theme;
 use crate::ui::modals::{
     ModalButtonComponent, ModalButtonComponentStyles, ModalComponent, ModalUtils,
 };
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use ratatui::layout::{Alignment, Constraint, Layout};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::prelude::{Line, Modifier, Span, Style};
 use ratatui::widgets::{Block, Paragraph};
 use ratatui::Frame;
@@ -19,6 +20,8 @@ pub struct TextInputModal {
     pub selected_ok: bool,
     pub placeholder: String,
     pub max_length: Option<usize>,
+    pub allow_empty: bool, // Submit an empty input instead of ignoring Enter
+    button_areas: Vec<Rect>,
 }
 impl TextInputModal {
     const BASE_HEIGHT: u16 = 12;
@@ -33,6 +36,8 @@ impl TextInputModal {
             selected_ok: true,
             placeholder: String::new(),
             max_length: None,
+            allow_empty: false,
+            button_areas: Vec::new(),
         }
     }
 
@@ -41,6 +46,11 @@ impl TextInputModal {
         self
     }
 
+    pub fn with_allow_empty(mut self) -> Self {
+        self.allow_empty = true;
+        self
+    }
+
     pub fn with_initial_value(mut self, value: impl Into<String>) -> Self {
         self.input_buffer = value.into();
         self.cursor_position = self.input_buffer.len();
@@ -75,7 +85,7 @@ impl TextInputModal {
             }
         } else {
             spans.push(Span::styled(
-                "█",
+                glyphs().cursor,
                 Style::default()
                     .fg(theme.input_cursor)
                     .add_modifier(Modifier::SLOW_BLINK),
@@ -86,6 +96,17 @@ impl TextInputModal {
     }
 }
 impl ModalComponent for TextInputModal {
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<ModalResponse> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+
+        // Clicking a button selects and activates it.
+        let index = ModalUtils::clicked_button(&self.button_areas, mouse)?;
+        self.selected_ok = index == 0;
+        self.handle_key(KeyEvent::from(KeyCode::Enter))
+    }
+
     fn handle_key(&mut self, key: KeyEvent) -> Option<ModalResponse> {
         match key.code {
             KeyCode::Esc => return Some(ModalResponse::Dismissed),
@@ -96,13 +117,14 @@ impl ModalComponent for TextInputModal {
                 return Some(ModalResponse::TextInput(Some(self.input_buffer.clone())))
             }
             KeyCode::Enter => {
-                return if self.selected_ok && !self.input_buffer.trim().is_empty() {
+                let has_input = self.allow_empty || !self.input_buffer.trim().is_empty();
+                return if self.selected_ok && has_input {
                     Some(ModalResponse::TextInput(Some(self.input_buffer.clone())))
                 } else if !self.selected_ok {
                     Some(ModalResponse::Dismissed)
                 } else {
                     None
-                }
+                };
             }
             KeyCode::Left if key.modifiers.contains(KeyModifiers::ALT) => {
                 self.selected_ok = true;
@@ -157,9 +179,11 @@ impl ModalComponent for TextInputModal {
         let button_styles = ModalButtonComponentStyles::from_theme(theme);
         let styled_buttons = ModalButtonComponent::create_ok_cancel_buttons(&button_styles);
 
+        // The content closure records the button areas, so it can't borrow the title too
+        let title = self.title.clone();
         ModalUtils::render_base(
             frame,
-            &self.title,
+            &title,
             |frame, area, theme| {
                 let with_spacer = Self::MINIMUM_HEIGHT + 1; // 7
                 let with_counter = with_spacer + 1; // 8 (only if max_length is set)
@@ -204,7 +228,7 @@ impl ModalComponent for TextInputModal {
                     vec![Line::from(vec![
                         Span::styled(&self.placeholder, Style::default().fg(theme.text_muted)),
                         Span::styled(
-                            "█",
+                            glyphs().cursor,
                             Style::default()
                                 .fg(theme.input_cursor)
                                 .add_modifier(Modifier::SLOW_BLINK),
@@ -247,7 +271,7 @@ impl ModalComponent for TextInputModal {
 
                 // Buttons
                 let selected_index = usize::from(!self.selected_ok);
-                ModalUtils::render_buttons(
+                self.button_areas = ModalUtils::render_buttons(
                     frame,
                     layout[layout_index],
                     &styled_buttons,
@@ -257,10 +281,12 @@ impl ModalComponent for TextInputModal {
 
                 // Help text
                 if show_help {
-                    let help =
-                        Paragraph::new("(Tab/Alt+←→) switch | (Enter) confirm | (Esc) cancel")
-                            .style(theme.secondary_style)
-                            .alignment(Alignment::Center);
+                    let help = Paragraph::new(format!(
+                        "(Tab/Alt+{}) switch | (Enter) confirm | (Esc) cancel",
+                        glyphs().keys_left_right
+                    ))
+                    .style(theme.secondary_style)
+                    .alignment(Alignment::Center);
                     frame.render_widget(help, layout[layout_index]);
                 }
             },
@@ -270,3 +296,30 @@ impl ModalComponent for TextInputModal {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TextInputModal;
+    use crate::modals::ModalResponse;
+    use crate::ui::modals::ModalComponent;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn enter(modal: &mut TextInputModal) -> Option<ModalResponse> {
+        modal.handle_key(KeyEvent::from(KeyCode::Enter))
+    }
+
+    #[test]
+    fn enter_ignores_empty_input_by_default() {
+        let mut modal = TextInputModal::new("Title", "Prompt").with_initial_value("  ");
+        assert_eq!(enter(&mut modal), None);
+    }
+
+    #[test]
+    fn enter_submits_empty_input_when_allowed() {
+        let mut modal = TextInputModal::new("Title", "Prompt").with_allow_empty();
+        assert_eq!(
+            enter(&mut modal),
+            Some(ModalResponse::TextInput(Some(String::new())))
+        );
+    }
+}
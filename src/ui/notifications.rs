@@ -1,9 +1,13 @@
 use crate::error::AppResult;
+use crate::glyphs::glyphs;
+use crate::modals::{AppModal, ModalMetadata};
+use crate::sanitize::sanitize_text;
 use crate::theme::Theme;
-use crate::types::AppAction;
+use crate::types::{AppAction, ContentNewlines};
+use crate::ui::modals::text_input::TextInputModal;
 use crate::ui::views::ViewStateRequest;
 use crate::ui::ViewBase;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::layout::{Alignment, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
@@ -17,6 +21,7 @@ use std::time::{Duration, Instant};
 pub enum NotificationType {
     IncomingMessage {
         phone: String,
+        friendly_name: Option<String>,
         content: String,
     },
     OnlineStatus {
@@ -24,6 +29,10 @@ pub enum NotificationType {
         current: ModemStatusUpdateState,
     },
     WebSocketConnectionUpdate(WebsocketReconnectionKind),
+    WebSocketDisabled {
+        uri: String,
+        fell_back: bool, // Auto mode couldn't connect, rather than being turned off
+    },
     Failure {
         title: String,
         message: String,
@@ -40,6 +49,8 @@ pub enum NotificationType {
 pub struct NotificationMessage {
     pub notification_type: NotificationType,
     pub timestamp: Instant,
+    pub count: usize, // Incoming messages coalesced into this one
+    pub pinned: bool, // Kept until dismissed, rather than expiring
 }
 impl NotificationMessage {
     pub fn get_phone_number(&self) -> Option<String> {
@@ -47,6 +58,7 @@ impl NotificationMessage {
             NotificationType::IncomingMessage { phone, .. } => Some(phone.clone()),
             NotificationType::OnlineStatus { .. }
             | NotificationType::WebSocketConnectionUpdate { .. }
+            | NotificationType::WebSocketDisabled { .. }
             | NotificationType::Failure { .. }
             | NotificationType::GenericMessage { .. } => None,
         }
@@ -59,8 +71,37 @@ impl NotificationMessage {
         )
     }
 
+    pub fn can_enable_websocket(&self) -> bool {
+        matches!(
+            self.notification_type,
+            NotificationType::WebSocketDisabled { .. }
+        )
+    }
+
+    /// Is this a message from a number without a friendly name.
+    pub fn can_name(&self) -> bool {
+        matches!(
+            self.notification_type,
+            NotificationType::IncomingMessage {
+                friendly_name: None,
+                ..
+            }
+        )
+    }
+
     pub fn is_expired(&self, display_duration: Duration) -> bool {
-        self.timestamp.elapsed() > display_duration
+        !self.pinned && self.timestamp.elapsed() > display_duration
+    }
+
+    /// How long ago the notification arrived, eg: "just now" or "3m".
+    fn age(&self) -> String {
+        let seconds = self.timestamp.elapsed().as_secs();
+        match seconds {
+            0..=9 => "just now".to_string(),
+            10..=59 => format!("{seconds}s"),
+            60..=3599 => format!("{}m", seconds / 60),
+            _ => format!("{}h", seconds / 3600),
+        }
     }
 }
 
@@ -75,22 +116,27 @@ struct RenderContext<'a> {
     theme: &'a Theme,
     opacity_modifier: Modifier,
     is_top: bool,
+    is_focused: bool,
 }
 
 fn get_notification_style(notification: &NotificationMessage, theme: &Theme) -> NotificationStyle {
     match &notification.notification_type {
         NotificationType::IncomingMessage { .. } => NotificationStyle {
-            icon: "📨".to_string(),
-            title: "New Message".to_string(),
+            icon: glyphs().new_message.to_string(),
+            title: if notification.count > 1 {
+                format!("{} New Messages", notification.count)
+            } else {
+                "New Message".to_string()
+            },
             border_color: theme.text_accent,
             title_color: theme.text_accent,
         },
         NotificationType::OnlineStatus { current, .. } => {
             let (icon, color) = match current {
-                ModemStatusUpdateState::Online => ("🟢", Color::Green),
-                ModemStatusUpdateState::Offline => ("🔴", Color::Red),
+                ModemStatusUpdateState::Online => (glyphs().status_online, Color::Green),
+                ModemStatusUpdateState::Offline => (glyphs().status_offline, Color::Red),
                 ModemStatusUpdateState::Startup | ModemStatusUpdateState::ShuttingDown => {
-                    ("🟡", Color::Yellow)
+                    (glyphs().status_changing, Color::Yellow)
                 }
             };
             NotificationStyle {
@@ -102,11 +148,19 @@ fn get_notification_style(notification: &NotificationMessage, theme: &Theme) ->
         }
         NotificationType::WebSocketConnectionUpdate(kind) => {
             let (icon, title, color) = match kind {
-                WebsocketReconnectionKind::Connected => ("🔗", "WebSocket Connected", Color::Green),
-                WebsocketReconnectionKind::Disconnected(reconnecting) if *reconnecting => {
-                    ("🔄", "WebSocket Reconnecting", Color::Yellow)
+                WebsocketReconnectionKind::Connected => {
+                    (glyphs().ws_connected, "WebSocket Connected", Color::Green)
                 }
-                _ => ("⚠️", "WebSocket Disconnected", Color::Red),
+                WebsocketReconnectionKind::Disconnected(reconnecting) if *reconnecting => (
+                    glyphs().ws_reconnecting,
+                    "WebSocket Reconnecting",
+                    Color::Yellow,
+                ),
+                _ => (
+                    glyphs().ws_disconnected,
+                    "WebSocket Disconnected",
+                    Color::Red,
+                ),
             };
 
             NotificationStyle {
@@ -116,9 +170,15 @@ fn get_notification_style(notification: &NotificationMessage, theme: &Theme) ->
                 title_color: color,
             }
         }
+        NotificationType::WebSocketDisabled { .. } => NotificationStyle {
+            title: "WebSocket Disabled".to_string(),
+            icon: glyphs().failure.to_string(),
+            border_color: Color::Red,
+            title_color: Color::Red,
+        },
         NotificationType::Failure { title, .. } => NotificationStyle {
             title: title.into(),
-            icon: "❌".to_string(),
+            icon: glyphs().failure.to_string(),
             border_color: Color::Red,
             title_color: Color::Red,
         },
@@ -136,9 +196,14 @@ fn get_notification_style(notification: &NotificationMessage, theme: &Theme) ->
 fn calculate_notification_height(notification: &NotificationMessage, is_top: bool) -> u16 {
     let base_height = match &notification.notification_type {
         NotificationType::IncomingMessage { content, .. } => {
-            let content_lines = (u16::try_from(content.len()).unwrap_or(0) / 45).clamp(1, 3);
-            5 + content_lines
+            // Wrapped as it's rendered, so wide characters take their full width
+            let content = sanitize_text(content, ContentNewlines::Strip);
+            let wrapped = textwrap::wrap(&content, NotificationsView::TEXTWRAP_MAX_WIDTH).len();
+            let max_lines = NotificationsView::INCOMING_MESSAGE_MAX_LINES;
+            let content_lines = wrapped.clamp(1, max_lines) + usize::from(wrapped > max_lines);
+            5 + u16::try_from(content_lines).unwrap_or(0)
         }
+        NotificationType::WebSocketDisabled { .. } => 4,
         NotificationType::OnlineStatus { .. }
         | NotificationType::WebSocketConnectionUpdate { .. }
         | NotificationType::Failure { .. }
@@ -155,43 +220,159 @@ fn calculate_notification_height(notification: &NotificationMessage, is_top: boo
 
 pub struct NotificationsView {
     notifications: Vec<NotificationMessage>,
+    focused: Option<usize>, // Card picked with Shift+F1 for F1-F3 to act on, newest is 0
     display_duration: Duration,
     max_notifications: usize,
 }
 impl NotificationsView {
     const TEXTWRAP_MAX_WIDTH: usize = 50;
     const INCOMING_MESSAGE_MAX_LINES: usize = 3;
+    const COALESCE_WINDOW: Duration = Duration::from_secs(5);
 
     pub fn new() -> Self {
         Self {
             notifications: Vec::new(),
+            focused: None,
             display_duration: Duration::from_secs(15),
             max_notifications: 6,
         }
     }
 
     pub fn add_notification(&mut self, notification_type: NotificationType) {
+        if self.coalesce(&notification_type) {
+            return;
+        }
+
         let notification = NotificationMessage {
             notification_type,
             timestamp: Instant::now(),
+            count: 1,
+            pinned: false,
         };
 
-        // Push and truncate end to maintain max size.
+        // Push and evict the oldest unpinned cards to maintain max size, keeping the same
+        // card focused. Pinned cards still count towards the max, but pinning always leaves
+        // a slot free so there's an unpinned card to evict.
         self.notifications.insert(0, notification);
-        if self.notifications.len() > self.max_notifications {
-            self.notifications.truncate(self.max_notifications);
+        self.focused = self.focused.map(|idx| idx + 1);
+        while self.notifications.len() > self.max_notifications {
+            let Some(idx) = self.notifications.iter().rposition(|n| !n.pinned) else {
+                break;
+            };
+            self.remove(idx);
         }
     }
 
+    /// Remove a card, keeping the same card focused if it wasn't this one.
+    fn remove(&mut self, idx: usize) {
+        self.notifications.remove(idx);
+        self.focused = match self.focused {
+            Some(focused) if focused == idx => None,
+            Some(focused) if focused > idx => Some(focused - 1),
+            focused => focused,
+        };
+    }
+
+    /// Pin or unpin the focused card, otherwise the most recent. A new pin is refused once
+    /// every slot but one is pinned, so new cards can still be shown.
+    fn toggle_pinned(&mut self) {
+        let idx = self.focused.unwrap_or(0);
+        let pinned = self.notifications.iter().filter(|n| n.pinned).count();
+        let pin_limit = self.max_notifications.saturating_sub(1);
+        if self.notifications.get(idx).is_some_and(|n| !n.pinned) && pinned >= pin_limit {
+            self.add_notification(NotificationType::Failure {
+                title: "Too Many Pinned".to_string(),
+                message: format!("Unpin a notification first, at most {pin_limit} can be pinned."),
+            });
+            return;
+        }
+
+        if let Some(notification) = self.notifications.get_mut(idx) {
+            notification.pinned = !notification.pinned;
+            // Unpinned cards expire as normal from now on.
+            notification.timestamp = Instant::now();
+        }
+    }
+
+    /// Merge a message into the most recent notification if it's from the same number and
+    /// arrived shortly after, previewing the newest message and restarting its expiry.
+    fn coalesce(&mut self, notification_type: &NotificationType) -> bool {
+        let NotificationType::IncomingMessage { phone, content, .. } = notification_type else {
+            return false;
+        };
+        let Some(latest) = self.notifications.first_mut() else {
+            return false;
+        };
+        if latest.timestamp.elapsed() > Self::COALESCE_WINDOW {
+            return false;
+        }
+
+        match &mut latest.notification_type {
+            NotificationType::IncomingMessage {
+                phone: latest_phone,
+                content: latest_content,
+                ..
+            } if latest_phone == phone => {
+                latest_content.clone_from(content);
+                latest.count += 1;
+                latest.timestamp = Instant::now();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    /// Show a newly assigned friendly name on any messages from that number.
+    pub fn set_friendly_name(&mut self, number: &str, name: &str) {
+        for notification in &mut self.notifications {
+            if let NotificationType::IncomingMessage {
+                phone,
+                friendly_name,
+                ..
+            } = &mut notification.notification_type
+            {
+                if phone == number {
+                    *friendly_name = Some(name.to_string());
+                }
+            }
+        }
+    }
+
+    /// Dismiss every card except the pinned ones.
     fn dismiss_all(&mut self) {
-        if !self.notifications.is_empty() {
-            self.notifications.clear();
+        self.notifications.retain(|n| n.pinned);
+        self.focused = None;
+    }
+
+    /// Focus the next older card, wrapping back to none after the oldest.
+    fn cycle_focus(&mut self) {
+        self.focused = match self.focused {
+            None if !self.notifications.is_empty() => Some(0),
+            Some(idx) if idx + 1 < self.notifications.len() => Some(idx + 1),
+            _ => None,
+        };
+    }
+
+    /// Dismiss the focused card, or the oldest if none is focused.
+    fn dismiss_focused(&mut self) {
+        match self.focused {
+            Some(idx) if idx < self.notifications.len() => self.remove(idx),
+            _ => self.dismiss_oldest(),
         }
     }
 
+    /// The card that F2 and F3 act on: the focused one, otherwise the most recent.
+    fn target(&self) -> Option<&NotificationMessage> {
+        self.notifications.get(self.focused.unwrap_or(0))
+    }
+
     fn dismiss_oldest(&mut self) {
-        if !self.notifications.is_empty() {
-            self.notifications.pop();
+        if let Some(idx) = self.notifications.len().checked_sub(1) {
+            self.remove(idx);
         }
     }
 
@@ -204,12 +385,29 @@ impl NotificationsView {
         frame.render_widget(Clear, area);
 
         let style = get_notification_style(notification, ctx.theme);
-        let title = format!(" {} {} ", style.icon, style.title);
+        let title = if notification.pinned {
+            format!(" {} {} {} ", style.icon, style.title, glyphs().pinned)
+        } else {
+            format!(" {} {} ", style.icon, style.title)
+        };
+        // Drawn in the border, so it doesn't change the card's height.
+        let age = Line::from(Span::styled(
+            format!(" {} ", notification.age()),
+            Style::default()
+                .fg(ctx.theme.text_muted)
+                .add_modifier(Modifier::DIM),
+        ))
+        .right_aligned();
         let block = Block::bordered()
             .title(title)
+            .title_top(age)
             .title_style(Style::default().fg(style.title_color))
             .title_alignment(Alignment::Left)
-            .border_type(BorderType::Rounded)
+            .border_type(if ctx.is_focused {
+                BorderType::Thick
+            } else {
+                BorderType::Rounded
+            })
             .border_style(
                 Style::default()
                     .fg(style.border_color)
@@ -241,14 +439,27 @@ impl NotificationsView {
             .add_modifier(ctx.opacity_modifier);
 
         match &notification.notification_type {
-            NotificationType::IncomingMessage { phone, content } => {
+            NotificationType::IncomingMessage {
+                phone,
+                friendly_name,
+                content,
+            } => {
+                // Message content and senders are untrusted, so never draw them raw.
+                let phone = sanitize_text(phone, ContentNewlines::Strip);
+                let content = sanitize_text(content, ContentNewlines::Strip);
+                let from = match friendly_name {
+                    Some(name) => {
+                        format!("{} ({phone})", sanitize_text(name, ContentNewlines::Strip))
+                    }
+                    None => phone,
+                };
                 lines.push(Line::from(vec![
                     Span::styled("From: ", muted_style),
-                    Span::styled(phone.clone(), accent_style),
+                    Span::styled(from, accent_style),
                 ]));
                 lines.push(Line::raw(""));
 
-                let wrapped_lines = textwrap::wrap(content, Self::TEXTWRAP_MAX_WIDTH);
+                let wrapped_lines = textwrap::wrap(&content, Self::TEXTWRAP_MAX_WIDTH);
                 let mut content_lines_added = 0;
 
                 for wrapped_line in wrapped_lines.iter().take(Self::INCOMING_MESSAGE_MAX_LINES) {
@@ -276,7 +487,7 @@ impl NotificationsView {
             } => {
                 lines.push(Line::from(vec![
                     Span::styled(previous_state.to_string(), muted_style),
-                    Span::styled(" → ", muted_style),
+                    Span::styled(format!(" {} ", glyphs().arrow_right), muted_style),
                     Span::styled(current_state.to_string(), accent_style),
                 ]));
             }
@@ -293,6 +504,21 @@ impl NotificationsView {
                     base_style,
                 )));
             }
+            NotificationType::WebSocketDisabled { uri, fell_back } => {
+                lines.push(Line::from(Span::styled(
+                    "Live updates will not show!",
+                    base_style,
+                )));
+                let hint = if *fell_back {
+                    "Couldn't connect to "
+                } else {
+                    "Enable with --ws-enabled for "
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(hint, muted_style),
+                    Span::styled(uri.clone(), accent_style),
+                ]));
+            }
             NotificationType::Failure { message, .. }
             | NotificationType::GenericMessage { message, .. } => {
                 lines.push(Line::from(Span::styled(message.clone(), base_style)));
@@ -303,12 +529,19 @@ impl NotificationsView {
         if ctx.is_top {
             lines.push(Line::raw(""));
 
-            // Only show "(Enter) view" for notifications that can be viewed
-            let controls_text = if notification.can_view() {
-                "(F1) dismiss • (F2) view"
+            // Only show "(F2) view" and "(F4) reply" for notifications that can be viewed
+            // Kept to one line, so some leave out the pin or focus hints.
+            let focus = format!("({}F1) focus", glyphs().shift);
+            let controls: &[&str] = if notification.can_enable_websocket() {
+                &["(F1) dismiss", "(F4) connect", "(F6) pin"]
+            } else if notification.can_name() {
+                &["(F1) dismiss", "(F2) view", "(F3) name", "(F4) reply"]
+            } else if notification.can_view() {
+                &["(F1) dismiss", "(F2) view", "(F4) reply", "(F6) pin"]
             } else {
-                "(F1) dismiss"
+                &["(F1) dismiss", "(F6) pin", &focus]
             };
+            let controls_text = controls.join(&format!(" {} ", glyphs().bullet));
 
             lines.push(Line::from(Span::styled(
                 controls_text,
@@ -330,14 +563,19 @@ impl ViewBase for NotificationsView {
 
     async fn handle_key(&mut self, key: KeyEvent, _ctx: Self::Context<'_>) -> Option<AppAction> {
         match key.code {
+            KeyCode::F(1) if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.cycle_focus();
+            }
             KeyCode::F(1) => {
-                self.dismiss_oldest();
+                self.dismiss_focused();
+            }
+            KeyCode::F(6) => {
+                self.toggle_pinned();
             }
             KeyCode::F(2) => {
                 // Navigate to the most recent notification's conversation if it can be viewed
                 if let Some(phone_number) = self
-                    .notifications
-                    .first()
+                    .target()
                     .filter(|n| n.can_view())
                     .and_then(NotificationMessage::get_phone_number)
                 {
@@ -348,33 +586,75 @@ impl ViewBase for NotificationsView {
                     });
                 }
             }
+            KeyCode::F(4) => {
+                // Reply to the sender of the most recent notification if it can be viewed
+                if let Some(phone_number) = self
+                    .target()
+                    .filter(|n| n.can_view())
+                    .and_then(NotificationMessage::get_phone_number)
+                {
+                    self.dismiss_all();
+                    return Some(AppAction::SetViewState {
+                        state: ViewStateRequest::compose(&phone_number),
+                        dismiss_modal: false,
+                    });
+                }
+
+                // Otherwise, start the WebSocket from its disabled warning
+                let idx = self.focused.unwrap_or(0);
+                if self
+                    .notifications
+                    .get(idx)
+                    .is_some_and(NotificationMessage::can_enable_websocket)
+                {
+                    self.remove(idx);
+                    return Some(AppAction::EnableWebSocket);
+                }
+            }
+            KeyCode::F(3) => {
+                // Name the sender of the most recent notification if they don't have one
+                let phone_number = self
+                    .target()
+                    .filter(|n| n.can_name())
+                    .and_then(NotificationMessage::get_phone_number)?;
+
+                let ui = TextInputModal::new("Name Number", format!("Name for {phone_number}"))
+                    .with_max_length(50);
+                let modal = AppModal::new("name_number", ui)
+                    .with_metadata(ModalMetadata::PhoneNumber(phone_number));
+                return Some(AppAction::SetModal(Some(modal)));
+            }
             _ => {}
         }
 
         None
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, _ctx: Self::Context<'_>) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, _ctx: Self::Context<'_>) {
         // TODO: Should be calling this way less. No need to enforce expiry every frame.
         self.notifications
             .retain(|notification| !notification.is_expired(self.display_duration));
+        self.focused = self.focused.filter(|idx| *idx < self.notifications.len());
         if self.notifications.is_empty() {
             return;
         }
 
-        let area = frame.area();
         let mut y_offset = 1;
         let mut is_top = true;
 
-        for notification in &self.notifications {
+        for (idx, notification) in self.notifications.iter().enumerate() {
+            // While a card is focused, it's the only one not dimmed.
+            let is_focused = self.focused == Some(idx);
+            let is_highlighted = self.focused.map_or(is_top, |_| is_focused);
             let ctx = RenderContext {
                 theme,
-                opacity_modifier: if is_top {
+                opacity_modifier: if is_highlighted {
                     Modifier::empty()
                 } else {
                     Modifier::DIM
                 },
                 is_top,
+                is_focused,
             };
 
             // Position notifications from top-right
@@ -400,3 +680,128 @@ impl Default for NotificationsView {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn incoming(content: &str) -> NotificationMessage {
+        NotificationMessage {
+            notification_type: NotificationType::IncomingMessage {
+                phone: "+441234567890".to_string(),
+                friendly_name: None,
+                content: content.to_string(),
+            },
+            timestamp: Instant::now(),
+            count: 1,
+            pinned: false,
+        }
+    }
+
+    fn generic(title: &str) -> NotificationType {
+        NotificationType::GenericMessage {
+            color: Color::Green,
+            icon: String::new(),
+            title: title.to_string(),
+            message: String::new(),
+        }
+    }
+
+    fn titles(view: &NotificationsView) -> Vec<&str> {
+        view.notifications
+            .iter()
+            .map(|n| match &n.notification_type {
+                NotificationType::GenericMessage { title, .. } => title.as_str(),
+                _ => "",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn evicts_oldest_past_max() {
+        let mut view = NotificationsView::new();
+        view.max_notifications = 2;
+        for title in ["a", "b", "c"] {
+            view.add_notification(generic(title));
+        }
+        assert_eq!(titles(&view), ["c", "b"]);
+    }
+
+    #[test]
+    fn pinned_cards_count_towards_max_without_being_evicted() {
+        let mut view = NotificationsView::new();
+        view.max_notifications = 3;
+        view.add_notification(generic("a"));
+        view.add_notification(generic("b"));
+        for notification in &mut view.notifications {
+            notification.pinned = true;
+        }
+
+        // Both pinned cards stay, leaving a single slot for new cards.
+        for title in ["c", "d", "e"] {
+            view.add_notification(generic(title));
+        }
+        assert_eq!(titles(&view), ["e", "b", "a"]);
+    }
+
+    #[test]
+    fn pinning_leaves_a_free_slot() {
+        let mut view = NotificationsView::new();
+        view.max_notifications = 2;
+        view.add_notification(generic("a"));
+        view.add_notification(generic("b"));
+        view.toggle_pinned();
+        assert!(view.notifications[0].pinned);
+
+        // Pinning the other card would fill every slot, so it's refused with a warning that
+        // takes the oldest unpinned slot.
+        view.focused = Some(1);
+        view.toggle_pinned();
+        assert_eq!(titles(&view), ["", "b"]);
+        assert!(matches!(
+            view.notifications[0].notification_type,
+            NotificationType::Failure { .. }
+        ));
+        assert!(!view.notifications[0].pinned);
+        assert!(view.notifications[1].pinned);
+    }
+
+    #[test]
+    fn eviction_keeps_focus() {
+        let mut view = NotificationsView::new();
+        view.max_notifications = 2;
+        view.add_notification(generic("a"));
+        view.add_notification(generic("b"));
+        view.focused = Some(0);
+
+        view.add_notification(generic("c"));
+        assert_eq!(titles(&view), ["c", "b"]);
+        assert_eq!(view.focused, Some(1));
+    }
+
+    #[test]
+    fn incoming_height_wraps_wide_characters() {
+        // 20 wide characters fill 40 columns, which fits on one line.
+        assert_eq!(
+            calculate_notification_height(&incoming(&"你".repeat(20)), false),
+            6
+        );
+        // 30 of them fill 60 columns, wrapping onto a second line.
+        assert_eq!(
+            calculate_notification_height(&incoming(&"你".repeat(30)), false),
+            7
+        );
+        assert_eq!(
+            calculate_notification_height(&incoming(&"👋".repeat(30)), false),
+            7
+        );
+    }
+
+    #[test]
+    fn incoming_height_counts_truncation_line() {
+        // 100 wide characters wrap onto four lines, shown as three and a truncation line.
+        let notification = incoming(&"你".repeat(100));
+        assert_eq!(calculate_notification_height(&notification, false), 9);
+        assert_eq!(calculate_notification_height(&notification, true), 11);
+    }
+}
@@ -1,15 +1,20 @@
 use crate::app::AppContext;
+use crate::contacts::normalize_phone_number;
 use crate::error::AppResult;
+use crate::glyphs::glyphs;
 use crate::modals::{AppModal, ModalMetadata, ModalResponse};
+use crate::sanitize::sanitize_text;
 use crate::theme::Theme;
-use crate::types::AppAction;
+use crate::types::{AppAction, ContentNewlines};
 use crate::ui::modals::confirmation::ConfirmationModal;
-use crate::ui::modals::loading::LoadingModal;
+use crate::ui::modals::text_input::TextInputModal;
 use crate::ui::notifications::NotificationType;
+use crate::ui::views::messages::next_pending_id;
 use crate::ui::views::ViewStateRequest;
-use crate::ui::{centered_rect, ModalResponderComponent, ViewBase};
+use crate::ui::{centered_rect, KeyHint, ModalResponderComponent, ViewBase};
+use crate::MAX_SEND_TIMEOUT;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::prelude::Color;
 use ratatui::style::palette::tailwind;
 use ratatui::style::{Modifier, Style};
@@ -17,14 +22,91 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Clear, Paragraph, Wrap};
 use ratatui::Frame;
 use sms_client::types::sms::{SmsMessage, SmsOutgoingMessage};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
 
 const BASE_SEND_TIMEOUT: usize = 30;
 
+/// The most undo steps kept, the oldest are dropped first.
+const MAX_UNDO_HISTORY: usize = 100;
+
+/// Edits of the same kind closer together than this are undone as one step.
+const UNDO_COALESCE: Duration = Duration::from_secs(1);
+
+type Snapshot = (String, usize); // Text, cursor position
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+    DeleteWord,
+    NewLine,
+}
+
+/// The GSM 7-bit default alphabet, one septet each.
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+    ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// GSM 7-bit extension characters, two septets each as they're escaped.
+const GSM7_EXTENSION: &str = "\u{c}^{}\\[~]|€";
+
+/// How a message is encoded, which decides how much text fits in each SMS part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SmsEncoding {
+    Gsm7,
+    Ucs2, // Anything outside GSM 7-bit
+}
+impl SmsEncoding {
+    fn of(text: &str) -> Self {
+        if text
+            .chars()
+            .all(|c| GSM7_BASIC.contains(c) || GSM7_EXTENSION.contains(c))
+        {
+            Self::Gsm7
+        } else {
+            Self::Ucs2
+        }
+    }
+
+    /// The text's length in septets or UTF-16 code units.
+    fn units(self, text: &str) -> usize {
+        match self {
+            Self::Gsm7 => text
+                .chars()
+                .map(|c| if GSM7_EXTENSION.contains(c) { 2 } else { 1 })
+                .sum(),
+            Self::Ucs2 => text.encode_utf16().count(),
+        }
+    }
+
+    fn single_part(self) -> usize {
+        match self {
+            Self::Gsm7 => 160,
+            Self::Ucs2 => 70,
+        }
+    }
+
+    /// Each part of a multipart message loses some room to its header.
+    fn multi_part(self) -> usize {
+        match self {
+            Self::Gsm7 => 153,
+            Self::Ucs2 => 67,
+        }
+    }
+}
+
 pub struct ComposeView {
     context: AppContext,
     cursor_position: usize,
     sms_text_buffer: String,
+    initial_text: Option<String>,
+    scroll_offset: u16,
     is_sending: bool,
+    timeout_override: Option<u32>, // Seconds, set with Ctrl+T for this message only
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<(EditKind, Instant)>, // None after undoing or moving the cursor
 }
 impl ComposeView {
     pub fn with_context(context: AppContext) -> Self {
@@ -32,43 +114,316 @@ impl ComposeView {
             context,
             cursor_position: 0,
             sms_text_buffer: String::new(),
+            initial_text: None,
+            scroll_offset: 0,
             is_sending: false,
+            timeout_override: None,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            last_edit: None,
         }
     }
 
-    fn get_sms_count(char_count: usize) -> usize {
-        match char_count {
+    /// Seed the text buffer with some text once loaded.
+    pub fn with_initial_text(mut self, initial_text: Option<String>) -> Self {
+        self.initial_text = initial_text;
+        self
+    }
+
+    /// The unsent message text, if any.
+    pub fn draft(&self) -> Option<String> {
+        (!self.sms_text_buffer.is_empty()).then(|| self.sms_text_buffer.clone())
+    }
+
+    /// The recipient for the title: the friendly name if known, and the number normalized
+    /// if it looks valid. Unknown numbers get a hint that they're a new contact, unless the
+    /// contact cache hasn't loaded yet, in which case the raw number is shown as is.
+    fn title(&self, phone_number: &str, theme: &Theme) -> Line<'static> {
+        let contacts = &self.context.contacts;
+        let Some(known) = contacts.is_known(phone_number) else {
+            return Line::from(format!(" Compose SMS to {phone_number} "));
+        };
+
+        let number = normalize_phone_number(phone_number);
+        let display = number.as_deref().unwrap_or(phone_number);
+        let recipient = match contacts.friendly_name(phone_number) {
+            Some(name) => format!("{name} ({display})"),
+            None => display.to_string(),
+        };
+
+        let mut spans = vec![Span::raw(format!(
+            " Compose SMS to {} ",
+            sanitize_text(&recipient, ContentNewlines::Strip)
+        ))];
+        if number.is_none() {
+            spans.push(Span::styled("(invalid number) ", theme.error_style));
+        } else if !known {
+            spans.push(Span::styled(
+                "(new contact) ",
+                Style::default().fg(theme.text_muted),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Show a confirmation modal with message send metadata, if there's anything to send.
+    /// This calls handle_modal_response from async loop, which then sends the message.
+    fn confirm_send(&self, phone_number: &str) -> Option<AppAction> {
+        if self.sms_text_buffer.is_empty() {
+            return None;
+        }
+
+        let modal = AppModal::new(
+            "confirm_sms_send",
+            ConfirmationModal::new(format!("Send SMS to {phone_number}?")),
+        )
+        .with_metadata(ModalMetadata::SendMessage(
+            phone_number.to_owned(),
+            self.sms_text_buffer.clone(),
+        ));
+        Some(AppAction::SetModal(Some(modal)))
+    }
+
+    fn get_sms_count(text: &str) -> usize {
+        let encoding = SmsEncoding::of(text);
+        match encoding.units(text) {
             0 => 0,
-            1..=160 => 1,
-            _ => {
-                // For multipart messages, each part is 153 chars (7 chars for metadata)
-                char_count.div_ceil(153)
+            units if units <= encoding.single_part() => 1,
+            units => units.div_ceil(encoding.multi_part()),
+        }
+    }
+
+    /// Take the message from a confirmed send's metadata, locking the view until it's loaded
+    /// again. The modal is dismissed as soon as it's confirmed, but the view only changes once
+    /// the returned action is handled, so a second confirmation in between sends nothing.
+    fn lock_send(is_sending: &mut bool, metadata: &ModalMetadata) -> Option<(String, String)> {
+        let ModalMetadata::SendMessage(phone, content) = metadata else {
+            return None;
+        };
+        if std::mem::replace(is_sending, true) {
+            return None;
+        }
+        Some((phone.clone(), content.clone()))
+    }
+
+    /// Seconds to wait for the message to send: this message's override, then the
+    /// configured timeout, otherwise scaled by how many SMS parts it takes.
+    fn send_timeout(&self) -> Option<u32> {
+        self.timeout_override
+            .or(self.context.options.send_timeout)
+            .or_else(|| {
+                let parts = Self::get_sms_count(&self.sms_text_buffer);
+                u32::try_from(BASE_SEND_TIMEOUT * parts).ok()
+            })
+    }
+
+    /// Set this message's timeout from the input, clearing it if empty.
+    fn set_timeout_override(&mut self, input: &str) -> Option<AppAction> {
+        let input = input.trim();
+        if input.is_empty() {
+            self.timeout_override = None;
+            return Some(AppAction::SetModal(None));
+        }
+
+        match input.parse::<u32>() {
+            Ok(seconds) if (1..=MAX_SEND_TIMEOUT).contains(&seconds) => {
+                self.timeout_override = Some(seconds);
+                Some(AppAction::SetModal(None))
             }
+            _ => Some(AppAction::ShowNotification(NotificationType::Failure {
+                title: "Invalid Timeout".to_string(),
+                message: format!(
+                    "The send timeout must be between 1 and {MAX_SEND_TIMEOUT} seconds."
+                ),
+            })),
         }
     }
 
-    fn move_cursor_left(&mut self) {
-        self.cursor_position = self.cursor_position.saturating_sub(1);
+    /// Snapshot the text before an edit, unless it continues a quick run of the same kind
+    /// of edit, so typing (or deleting) a word is undone in one step. New lines and word
+    /// deletions always start their own step.
+    fn record_edit(&mut self, kind: EditKind) {
+        let now = Instant::now();
+        let continues = self.last_edit.is_some_and(|(last, at)| {
+            last == kind
+                && matches!(kind, EditKind::Insert | EditKind::Delete)
+                && now.duration_since(at) < UNDO_COALESCE
+        });
+        if !continues {
+            let snapshot = (self.sms_text_buffer.clone(), self.cursor_position);
+            self.push_undo(snapshot);
+        }
+
+        self.last_edit = Some((kind, now));
+        self.redo.clear();
+    }
+
+    fn push_undo(&mut self, snapshot: Snapshot) {
+        if self.undo.len() >= MAX_UNDO_HISTORY {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(snapshot);
+    }
+
+    /// Swap in a snapshot's text and cursor, returning the ones replaced.
+    fn restore_snapshot(&mut self, (text, cursor): Snapshot) -> Snapshot {
+        let previous = (
+            std::mem::replace(&mut self.sms_text_buffer, text),
+            self.cursor_position,
+        );
+        self.cursor_position = cursor;
+        self.last_edit = None;
+        previous
     }
 
-    fn move_cursor_right(&mut self, text_len: usize) {
-        if self.cursor_position < text_len {
-            self.cursor_position += 1;
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo.pop_back() {
+            let current = self.restore_snapshot(snapshot);
+            self.redo.push(current);
         }
     }
 
+    fn redo(&mut self) {
+        if let Some(snapshot) = self.redo.pop() {
+            let current = self.restore_snapshot(snapshot);
+            self.push_undo(current);
+        }
+    }
+
+    fn is_word_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    /// The byte index of the start of the word before `position`, skipping any
+    /// whitespace or punctuation in between.
+    fn previous_word_boundary(text: &str, position: usize) -> usize {
+        let mut chars = text[..position].char_indices().rev().peekable();
+        while chars.next_if(|(_, c)| !Self::is_word_char(*c)).is_some() {}
+        while chars.next_if(|(_, c)| Self::is_word_char(*c)).is_some() {}
+        chars.peek().map_or(0, |(i, c)| i + c.len_utf8())
+    }
+
+    /// The byte index of the end of the word after `position`, skipping any
+    /// whitespace or punctuation in between.
+    fn next_word_boundary(text: &str, position: usize) -> usize {
+        let mut chars = text[position..].char_indices().peekable();
+        while chars.next_if(|(_, c)| !Self::is_word_char(*c)).is_some() {}
+        while chars.next_if(|(_, c)| Self::is_word_char(*c)).is_some() {}
+        chars.peek().map_or(text.len(), |(i, _)| position + i)
+    }
+
+    /// The byte index of the character before `position`, which must be on a char boundary.
+    fn previous_char_boundary(text: &str, position: usize) -> usize {
+        text[..position]
+            .chars()
+            .next_back()
+            .map_or(0, |c| position - c.len_utf8())
+    }
+
+    /// The byte index after the character at `position`, which must be on a char boundary.
+    fn next_char_boundary(text: &str, position: usize) -> usize {
+        text[position..]
+            .chars()
+            .next()
+            .map_or(text.len(), |c| position + c.len_utf8())
+    }
+
+    fn delete_word_before_cursor(&mut self) {
+        let end = self.cursor_position;
+        let start = Self::previous_word_boundary(&self.sms_text_buffer, end);
+        if start < end {
+            self.record_edit(EditKind::DeleteWord);
+            self.sms_text_buffer.replace_range(start..end, "");
+            self.cursor_position = start;
+        }
+    }
+
+    fn delete_word_after_cursor(&mut self) {
+        let start = self.cursor_position;
+        let end = Self::next_word_boundary(&self.sms_text_buffer, start);
+        if start < end {
+            self.record_edit(EditKind::DeleteWord);
+            self.sms_text_buffer.replace_range(start..end, "");
+            self.cursor_position = start;
+        }
+    }
+
+    // The cursor is a byte index into the text, only ever moved by whole characters so it
+    // always sits on a char boundary.
+
+    fn move_cursor_left(&mut self) {
+        self.cursor_position =
+            Self::previous_char_boundary(&self.sms_text_buffer, self.cursor_position);
+    }
+
+    fn move_cursor_right(&mut self) {
+        self.cursor_position =
+            Self::next_char_boundary(&self.sms_text_buffer, self.cursor_position);
+    }
+
     fn move_cursor_to_start(&mut self) {
         self.cursor_position = 0;
     }
 
-    fn move_cursor_to_end(&mut self, text_len: usize) {
-        self.cursor_position = text_len;
+    fn move_cursor_to_end(&mut self) {
+        self.cursor_position = self.sms_text_buffer.len();
+    }
+
+    fn insert_char(&mut self, c: char) {
+        self.sms_text_buffer.insert(self.cursor_position, c);
+        self.cursor_position += c.len_utf8();
+    }
+
+    /// Number of rows a line takes up once wrapped to the given width.
+    fn wrapped_rows(line: &str, width: usize) -> usize {
+        line.width().div_ceil(width).max(1)
+    }
+
+    /// Get the (wrapped) row the cursor is on, and the total row count.
+    fn cursor_row(&self, width: u16) -> (usize, usize) {
+        let width = usize::from(width.max(1));
+        let before_cursor = &self.sms_text_buffer[..self.cursor_position];
+
+        // Rows taken by every full line before the cursor's line.
+        let cursor_line_start = before_cursor.rfind('\n').map_or(0, |i| i + 1);
+        let rows_before: usize = self.sms_text_buffer[..cursor_line_start]
+            .split_terminator('\n')
+            .map(|line| Self::wrapped_rows(line, width))
+            .sum();
+
+        // The cursor itself takes a cell, so it wraps when sat at the end of a full row.
+        let cursor_row = rows_before + before_cursor[cursor_line_start..].width() / width;
+        let total_rows = self
+            .sms_text_buffer
+            .split('\n')
+            .map(|line| Self::wrapped_rows(line, width))
+            .sum::<usize>()
+            .max(cursor_row + 1);
+
+        (cursor_row, total_rows)
+    }
+
+    /// Keep the cursor row within the visible text area.
+    fn update_scroll(&mut self, width: u16, height: u16) {
+        let height = usize::from(height.max(1));
+        let (cursor_row, total_rows) = self.cursor_row(width);
+
+        let mut offset = usize::from(self.scroll_offset);
+        if cursor_row < offset {
+            offset = cursor_row;
+        } else if cursor_row >= offset + height {
+            offset = cursor_row + 1 - height;
+        }
+
+        // Don't leave empty space at the bottom if the text shrank.
+        offset = offset.min(total_rows.saturating_sub(height));
+        self.scroll_offset = u16::try_from(offset).unwrap_or(u16::MAX);
     }
 
     fn render_text_with_cursor(&self, theme: &Theme) -> Vec<Line<'static>> {
         if self.sms_text_buffer.is_empty() {
             return vec![Line::from(vec![Span::styled(
-                "█",
+                glyphs().cursor,
                 Style::default()
                     .fg(theme.input_cursor)
                     .add_modifier(Modifier::SLOW_BLINK),
@@ -92,21 +447,22 @@ impl ComposeView {
                     spans.push(Span::raw(line[..cursor_pos_in_line].to_string()));
                 }
 
-                if cursor_pos_in_line < line.len() {
+                if let Some(c) = line[cursor_pos_in_line..].chars().next() {
                     spans.push(Span::styled(
-                        line.chars().nth(cursor_pos_in_line).unwrap().to_string(),
+                        c.to_string(),
                         Style::default()
                             .fg(theme.bg)
                             .bg(theme.input_cursor)
                             .add_modifier(Modifier::SLOW_BLINK),
                     ));
 
-                    if cursor_pos_in_line + 1 < line.len() {
-                        spans.push(Span::raw(line[cursor_pos_in_line + 1..].to_string()));
+                    let after = cursor_pos_in_line + c.len_utf8();
+                    if after < line.len() {
+                        spans.push(Span::raw(line[after..].to_string()));
                     }
                 } else {
                     spans.push(Span::styled(
-                        "█",
+                        glyphs().cursor,
                         Style::default()
                             .fg(theme.input_cursor)
                             .add_modifier(Modifier::SLOW_BLINK),
@@ -124,7 +480,7 @@ impl ComposeView {
             && self.sms_text_buffer.ends_with('\n')
         {
             lines.push(Line::from(vec![Span::styled(
-                "█",
+                glyphs().cursor,
                 Style::default()
                     .fg(theme.input_cursor)
                     .add_modifier(Modifier::SLOW_BLINK),
@@ -138,9 +494,14 @@ impl ViewBase for ComposeView {
     type Context<'ctx> = &'ctx String;
 
     async fn load(&mut self, _ctx: Self::Context<'_>) -> AppResult<()> {
-        self.cursor_position = 0;
         self.is_sending = false;
-        self.sms_text_buffer.clear();
+        self.timeout_override = None;
+        self.sms_text_buffer = self.initial_text.take().unwrap_or_default();
+        self.cursor_position = self.sms_text_buffer.len();
+        self.scroll_offset = 0;
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
         Ok(())
     }
 
@@ -159,54 +520,92 @@ impl ViewBase for ComposeView {
                 });
             }
             KeyCode::Char(' ') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                if !self.sms_text_buffer.is_empty() {
-                    // Show a confirmation modal with message send metadata.
-                    // This calls handle_modal_response from async loop, which then sends the message.
-                    let modal = AppModal::new(
-                        "confirm_sms_send",
-                        ConfirmationModal::new(format!("Send SMS to {ctx}?")),
-                    )
-                    .with_metadata(ModalMetadata::SendMessage(
-                        ctx.to_owned(),
-                        self.sms_text_buffer.clone(),
-                    ));
-
-                    return Some(AppAction::SetModal(Some(modal)));
+                return self.confirm_send(ctx);
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let mut ui = TextInputModal::new(
+                    "Send Timeout",
+                    format!(
+                        "Seconds to wait for sending, 1-{MAX_SEND_TIMEOUT} (empty for default)"
+                    ),
+                )
+                .with_max_length(3)
+                .with_allow_empty();
+                if let Some(seconds) = self.timeout_override {
+                    ui = ui.with_initial_value(seconds.to_string());
                 }
+                return Some(AppAction::SetModal(Some(AppModal::new("send_timeout", ui))));
+            }
+            // Shift+Enter isn't reported by every terminal, so Alt+Enter also adds a new line.
+            KeyCode::Enter
+                if self.context.options.enter_sends
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT) =>
+            {
+                return self.confirm_send(ctx);
+            }
+            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.undo();
+            }
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.redo();
             }
             KeyCode::Enter => {
-                self.sms_text_buffer.push('\n');
-                self.move_cursor_right(self.sms_text_buffer.len());
+                self.record_edit(EditKind::NewLine);
+                self.insert_char('\n');
+            }
+            // Terminals tend to send Alt+Backspace for Ctrl+Backspace, so accept both.
+            KeyCode::Backspace
+                if key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.delete_word_before_cursor();
+            }
+            KeyCode::Delete if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.delete_word_after_cursor();
+            }
+            KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.last_edit = None;
+                self.cursor_position =
+                    Self::previous_word_boundary(&self.sms_text_buffer, self.cursor_position);
+            }
+            KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.last_edit = None;
+                self.cursor_position =
+                    Self::next_word_boundary(&self.sms_text_buffer, self.cursor_position);
             }
             KeyCode::Backspace => {
                 if self.cursor_position > 0 {
-                    let pos = self.cursor_position;
-                    self.sms_text_buffer.remove(pos - 1);
+                    self.record_edit(EditKind::Delete);
                     self.move_cursor_left();
+                    self.sms_text_buffer.remove(self.cursor_position);
                 }
             }
-            KeyCode::Delete => {
-                if self.cursor_position < self.sms_text_buffer.len() {
-                    let pos = self.cursor_position;
-                    self.sms_text_buffer.remove(pos);
-                }
+            KeyCode::Delete if self.cursor_position < self.sms_text_buffer.len() => {
+                self.record_edit(EditKind::Delete);
+                self.sms_text_buffer.remove(self.cursor_position);
             }
             KeyCode::Left => {
+                self.last_edit = None;
                 self.move_cursor_left();
             }
             KeyCode::Right => {
-                self.move_cursor_right(self.sms_text_buffer.len());
+                self.last_edit = None;
+                self.move_cursor_right();
             }
             KeyCode::Home => {
+                self.last_edit = None;
                 self.move_cursor_to_start();
             }
             KeyCode::End => {
-                self.move_cursor_to_end(self.sms_text_buffer.len());
+                self.last_edit = None;
+                self.move_cursor_to_end();
             }
             KeyCode::Char(c) => {
-                let pos = self.cursor_position;
-                self.sms_text_buffer.insert(pos, c);
-                self.move_cursor_right(self.sms_text_buffer.len());
+                self.record_edit(EditKind::Insert);
+                self.insert_char(c);
             }
             _ => {}
         }
@@ -214,12 +613,12 @@ impl ViewBase for ComposeView {
         None
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, ctx: Self::Context<'_>) {
-        let area = centered_rect(70, 60, frame.area());
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, ctx: Self::Context<'_>) {
+        let area = centered_rect(70, 60, area);
         frame.render_widget(Clear, area);
 
         let block = Block::bordered()
-            .title(format!(" Compose SMS to {ctx} "))
+            .title(self.title(ctx, theme))
             .title_alignment(Alignment::Center)
             .border_type(BorderType::Rounded)
             .border_style(theme.border_focused_style);
@@ -230,11 +629,14 @@ impl ViewBase for ComposeView {
         let layout = Layout::vertical([
             Constraint::Min(10),   // Text area
             Constraint::Length(2), // Character count
-            Constraint::Length(2), // Help text
         ])
         .split(inner);
 
-        // Text area with cursor
+        // Text area with cursor, scrolled to keep the cursor visible (inside borders)
+        self.update_scroll(
+            layout[0].width.saturating_sub(2),
+            layout[0].height.saturating_sub(2),
+        );
         let text_with_cursor = self.render_text_with_cursor(theme);
 
         let text_area = Paragraph::new(text_with_cursor)
@@ -245,43 +647,50 @@ impl ViewBase for ComposeView {
                     .border_type(BorderType::Rounded),
             )
             .wrap(Wrap { trim: false })
-            .scroll((0, 0));
+            .scroll((self.scroll_offset, 0));
 
         frame.render_widget(text_area, layout[0]);
 
-        // Character counter
-        let char_count = self.sms_text_buffer.chars().count();
-        let (counter_style, counter_text) = if char_count <= 160 {
-            (
+        // Character counter, in septets or UTF-16 code units depending on the encoding
+        let encoding = SmsEncoding::of(&self.sms_text_buffer);
+        let units = encoding.units(&self.sms_text_buffer);
+        let (counter_style, counter_text) = match Self::get_sms_count(&self.sms_text_buffer) {
+            0 | 1 => (
                 theme.accent_style.bg(theme.bg),
-                format!("{char_count}/160 (1 SMS)"),
-            )
-        } else if char_count <= 320 {
-            (
+                format!("{units}/{} (1 SMS)", encoding.single_part()),
+            ),
+            2 => (
                 Style::default().fg(tailwind::YELLOW.c400).bg(theme.bg),
-                format!("{char_count}/320 (2 SMS parts)"),
-            )
-        } else {
-            (
+                format!("{units}/{} (2 SMS parts)", encoding.multi_part() * 2),
+            ),
+            parts => (
                 theme.error_style.bg(theme.bg),
-                format!(
-                    "{} ({} SMS parts)",
-                    char_count,
-                    Self::get_sms_count(char_count)
-                ),
-            )
+                format!("{units} ({parts} SMS parts)"),
+            ),
         };
 
+        let counter_text = match self.send_timeout() {
+            Some(seconds) if units > 0 => format!("{counter_text} | {seconds}s timeout"),
+            _ => counter_text,
+        };
         let char_counter = Paragraph::new(counter_text)
             .style(counter_style)
             .alignment(Alignment::Right);
         frame.render_widget(char_counter, layout[1]);
+    }
 
-        // Help text
-        let help = Paragraph::new("(Enter) new line | (Ctrl+Space) send | (Esc) cancel")
-            .style(theme.secondary_style)
-            .alignment(Alignment::Center);
-        frame.render_widget(help, layout[2]);
+    fn key_hints(&self, _ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        let mut hints = if self.context.options.enter_sends {
+            vec![("Enter".into(), "send"), ("Shift+Enter".into(), "new line")]
+        } else {
+            vec![("Ctrl+Space".into(), "send"), ("Enter".into(), "new line")]
+        };
+        hints.extend([
+            ("Esc".into(), "cancel"),
+            ("Ctrl+Z/Y".into(), "undo/redo"),
+            ("Ctrl+T".into(), "timeout"),
+        ]);
+        hints
     }
 }
 impl ModalResponderComponent for ComposeView {
@@ -292,57 +701,191 @@ impl ModalResponderComponent for ComposeView {
     ) -> Option<AppAction> {
         match response {
             ModalResponse::Confirmed if modal.id == "confirm_sms_send" => {}
+            ModalResponse::TextInput(Some(input)) if modal.id == "send_timeout" => {
+                return self.set_timeout_override(&input);
+            }
             _ => return None,
         }
 
-        // Ensure it's a SendMessage metadata
-        let (phone, content) = match &modal.metadata {
-            ModalMetadata::SendMessage(phone, content) => (phone.clone(), content.clone()),
-            _ => return None,
-        };
-
-        let http = self.context.0.clone();
-        let sender = self.context.1.clone();
+        let (phone, content) = Self::lock_send(&mut self.is_sending, &modal.metadata)?;
+        let http = self.context.http.clone();
+        let sender = self.context.sender.clone();
+        let queue = self.context.send_queue.clone();
+        let timeout = self.send_timeout();
+        let parts = Self::get_sms_count(&content);
+
+        // Show the message in the conversation straight away, until the send finishes.
+        let state = ViewStateRequest::view_messages(&phone);
+        let pending_id = next_pending_id();
+        let _ = sender.send(AppAction::AddPendingMessage {
+            phone_number: phone.clone(),
+            pending_id,
+            content: content.clone(),
+            parts,
+        });
 
         tokio::spawn(async move {
-            let length = content.len();
             let mut message = SmsOutgoingMessage::simple_message(phone.clone(), content);
-            if let Ok(timeout) = u32::try_from(BASE_SEND_TIMEOUT * Self::get_sms_count(length)) {
+            if let Some(timeout) = timeout {
                 message = message.with_timeout(timeout);
             }
 
-            // Send the SMS message
-            let notification = match http.send_sms(&message).await {
+            // Send the SMS message, waiting behind any others being sent
+            let (notification, message_id) = match queue.send(|| http.send_sms(&message)).await {
                 Ok(response) => {
                     // Push message to views to ensure its synced even if WebSocket is disabled
                     let stored_message = SmsMessage::from((message, response));
                     let _ = sender.send(AppAction::HandleMessage(stored_message));
 
-                    NotificationType::GenericMessage {
+                    let notification = NotificationType::GenericMessage {
                         color: Color::Green,
-                        icon: "✔️".to_string(),
+                        icon: glyphs().success.to_string(),
                         title: "Message Sent".to_string(),
                         message: format!(
                             "Message #{} was sent (ref {})!",
                             response.message_id, response.reference_id
                         ),
-                    }
+                    };
+                    (notification, Some(response.message_id))
+                }
+                Err(e) => {
+                    let notification = NotificationType::Failure {
+                        title: "Send Failure".to_string(),
+                        message: e.to_string(),
+                    };
+                    (notification, None)
                 }
-                Err(e) => NotificationType::Failure {
-                    title: "Send Failure".to_string(),
-                    message: e.to_string(),
-                },
             };
 
-            let _ = sender.send(AppAction::ShowNotification(notification));
-            let _ = sender.send(AppAction::SetViewState {
-                state: ViewStateRequest::view_messages(&phone),
-                // Ensure the loading modal is dismissed on this state change.
-                dismiss_modal: true,
+            let _ = sender.send(AppAction::ResolvePendingMessage {
+                phone_number: phone,
+                pending_id,
+                message_id,
             });
+            let _ = sender.send(AppAction::ShowNotification(notification));
         });
 
-        let modal = AppModal::new("sms_sending", LoadingModal::new("Sending message..."));
-        Some(AppAction::SetModal(Some(modal)))
+        Some(AppAction::SetViewState {
+            state,
+            dismiss_modal: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComposeView;
+    use crate::modals::ModalMetadata;
+
+    #[test]
+    fn word_boundaries_skip_punctuation_runs() {
+        let text = "foo... bar!!! baz";
+        assert_eq!(ComposeView::previous_word_boundary(text, text.len()), 14);
+        assert_eq!(ComposeView::previous_word_boundary(text, 14), 7);
+        assert_eq!(ComposeView::previous_word_boundary(text, 7), 0);
+        assert_eq!(ComposeView::next_word_boundary(text, 0), 3);
+        assert_eq!(ComposeView::next_word_boundary(text, 3), 10);
+        assert_eq!(ComposeView::next_word_boundary(text, 10), text.len());
+    }
+
+    #[test]
+    fn word_boundaries_at_buffer_edges() {
+        assert_eq!(ComposeView::previous_word_boundary("", 0), 0);
+        assert_eq!(ComposeView::next_word_boundary("", 0), 0);
+        assert_eq!(ComposeView::previous_word_boundary("hello", 0), 0);
+        assert_eq!(ComposeView::next_word_boundary("hello", 5), 5);
+        assert_eq!(ComposeView::previous_word_boundary("  hello", 2), 0);
+        assert_eq!(ComposeView::next_word_boundary("hello  ", 5), 7);
+    }
+
+    #[test]
+    fn word_boundaries_with_multibyte_text() {
+        let text = "héllo wörld";
+        let world = text.find('w').unwrap();
+        assert_eq!(ComposeView::previous_word_boundary(text, text.len()), world);
+        assert_eq!(ComposeView::next_word_boundary(text, 0), world - 1);
+        assert_eq!(ComposeView::next_word_boundary(text, world - 1), text.len());
+    }
+
+    #[test]
+    fn word_boundaries_with_wide_chars() {
+        let text = "你好 世界 👋 ok";
+        let second = text.find('世').unwrap();
+        let ok = text.find("ok").unwrap();
+        assert_eq!(ComposeView::previous_word_boundary(text, text.len()), ok);
+        // The emoji isn't a word character, so it's skipped like punctuation.
+        assert_eq!(ComposeView::previous_word_boundary(text, ok), second);
+        assert_eq!(
+            ComposeView::next_word_boundary(text, second),
+            second + "世界".len()
+        );
+    }
+
+    #[test]
+    fn char_boundaries_step_whole_characters() {
+        let text = "aé你👋";
+        let steps = [0, 1, 3, 6, 10];
+        for pair in steps.windows(2) {
+            assert_eq!(ComposeView::next_char_boundary(text, pair[0]), pair[1]);
+            assert_eq!(ComposeView::previous_char_boundary(text, pair[1]), pair[0]);
+        }
+        assert_eq!(ComposeView::previous_char_boundary(text, 0), 0);
+        assert_eq!(
+            ComposeView::next_char_boundary(text, text.len()),
+            text.len()
+        );
+    }
+
+    #[test]
+    fn back_to_back_confirmations_send_once() {
+        let metadata = ModalMetadata::SendMessage("+441234567890".into(), "hello".into());
+        let mut is_sending = false;
+
+        let sends = (0..2)
+            .filter_map(|_| ComposeView::lock_send(&mut is_sending, &metadata))
+            .count();
+        assert_eq!(sends, 1);
+        assert!(is_sending);
+
+        // Loading the view again unlocks it for the next message.
+        is_sending = false;
+        assert!(ComposeView::lock_send(&mut is_sending, &metadata).is_some());
+    }
+
+    #[test]
+    fn other_metadata_does_not_lock() {
+        let mut is_sending = false;
+        assert_eq!(
+            ComposeView::lock_send(&mut is_sending, &ModalMetadata::None),
+            None
+        );
+        assert!(!is_sending);
+    }
+
+    #[test]
+    fn sms_count_for_gsm7_text() {
+        assert_eq!(ComposeView::get_sms_count(""), 0);
+        assert_eq!(ComposeView::get_sms_count(&"a".repeat(160)), 1);
+        assert_eq!(ComposeView::get_sms_count(&"a".repeat(161)), 2);
+        assert_eq!(ComposeView::get_sms_count(&"é".repeat(306)), 2);
+        assert_eq!(ComposeView::get_sms_count(&"é".repeat(307)), 3);
+        // Extension characters are escaped, taking two septets each.
+        assert_eq!(ComposeView::get_sms_count(&"€".repeat(80)), 1);
+        assert_eq!(ComposeView::get_sms_count(&"€".repeat(81)), 2);
+    }
+
+    #[test]
+    fn sms_count_for_ucs2_text() {
+        // Text outside GSM 7-bit is sent as UCS-2, 70 code units in a single part or 67 per
+        // part of a multipart message.
+        assert_eq!(ComposeView::get_sms_count(&"你".repeat(70)), 1);
+        assert_eq!(ComposeView::get_sms_count(&"你".repeat(71)), 2);
+        assert_eq!(ComposeView::get_sms_count(&"你".repeat(160)), 3);
+        // A single non-GSM character switches the whole message over.
+        let mixed = format!("{}你", "a".repeat(70));
+        assert_eq!(ComposeView::get_sms_count(&mixed), 2);
+        // Emoji take a surrogate pair, so two code units each.
+        assert_eq!(ComposeView::get_sms_count(&"👋".repeat(35)), 1);
+        assert_eq!(ComposeView::get_sms_count(&"👋".repeat(36)), 2);
     }
 }
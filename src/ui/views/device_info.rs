@@ -1,7 +1,6 @@
 use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::layout::{Alignment, Constraint, Layout};
-use ratatui::style::palette::tailwind;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Clear, Paragraph};
 use ratatui::Frame;
@@ -9,99 +8,147 @@ use sms_client::error::ClientError;
 use sms_client::types::http::{
     HttpModemBatteryLevelResponse, HttpModemSignalStrengthResponse, HttpSmsDeviceInfoResponse,
 };
+use std::time::{Duration, Instant};
 
 use crate::app::AppContext;
+use crate::device_status::{self, DeviceStatus};
 use crate::error::AppResult;
+use crate::glyphs::glyphs;
 use crate::theme::Theme;
 use crate::types::AppAction;
 use crate::ui::views::ViewStateRequest;
-use crate::ui::{centered_rect, ViewBase};
+use crate::ui::{centered_rect, KeyHint, ViewBase};
+
+/// Round trip time of the last device info request.
+#[derive(Debug, Clone, Copy)]
+enum ApiLatency {
+    Measured(Duration),
+    TimedOut,
+    Failed,
+}
 
 pub struct DeviceInfoView {
     context: AppContext,
     device_info: Option<HttpSmsDeviceInfoResponse>,
+    latency: Option<ApiLatency>,
+    show_details: bool, // BER, raw RSSI and dBm
 }
 impl DeviceInfoView {
     pub fn with_context(context: AppContext) -> Self {
+        // A preference toggled with (t) wins over the configured default.
+        let show_details = context
+            .state
+            .signal_details()
+            .unwrap_or(context.options.signal_details);
         Self {
             context,
             device_info: None,
+            latency: None,
+            show_details,
         }
     }
 
-    fn get_signal_strength_percentage(signal: HttpModemSignalStrengthResponse) -> u8 {
-        // Convert RSSI (0-31) to percentage
-        // RSSI 0 = -113 dBm (worst), RSSI 31 = -51 dBm (best)
-        // RSSI 99 = unknown
-        if signal.rssi == 99 {
-            0 // Unknown signal
-        } else if signal.rssi > 31 {
-            100 // Cap at 100% for invalid values
-        } else {
-            (signal.rssi as f32 / 31.0 * 100.0)
-                .clamp(0.0, 255.0)
-                .round() as u8
-        }
+    fn toggle_details(&mut self) {
+        self.show_details = !self.show_details;
+        self.context.state.set_signal_details(self.show_details);
     }
 
-    fn get_signal_quality_text(signal: HttpModemSignalStrengthResponse) -> (&'static str, Color) {
-        if signal.rssi == 99 {
-            return ("Unknown", Color::Gray);
+    /// Fetch the device info, timing the request and passing the status on to the
+    /// status bar.
+    async fn fetch(&mut self) -> AppResult<HttpSmsDeviceInfoResponse> {
+        let started = Instant::now();
+        let result = self.context.http.get_device_info().await;
+        self.latency = Some(match result {
+            Ok(_) => ApiLatency::Measured(started.elapsed()),
+            Err(_) => ApiLatency::Failed,
+        });
+        if let Ok(info) = &result {
+            let status = DeviceStatus::from(info);
+            let _ = self
+                .context
+                .sender
+                .send(AppAction::UpdateDeviceStatus(status));
         }
+        Ok(result.map_err(ClientError::from)?)
+    }
 
-        match Self::get_signal_strength_percentage(signal) {
-            90..=100 => ("Excellent", Color::Green),
-            70..=89 => ("Good", tailwind::LIME.c400),
-            50..=69 => ("Fair", Color::Yellow),
-            25..=49 => ("Poor", tailwind::ORANGE.c400),
-            _ => ("Very Poor", Color::Red),
+    /// Fetch the device info again, keeping what was shown before if it fails so the
+    /// latency line can report it instead.
+    async fn refresh(&mut self) {
+        let timeout = self.context.options.load_timeout;
+        match tokio::time::timeout(timeout, self.fetch()).await {
+            Ok(Ok(device_info)) => self.device_info = Some(device_info),
+            Ok(Err(_)) => {}
+            Err(_) => self.latency = Some(ApiLatency::TimedOut),
         }
     }
 
-    fn get_battery_status_text(battery: HttpModemBatteryLevelResponse) -> &'static str {
-        match battery.status {
-            0 => "Not Charging",
-            1 => "Charging",
-            2 => "No Battery",
-            _ => "Unknown",
-        }
+    fn render_connection_line(&self, theme: &Theme) -> Line<'static> {
+        let muted = Style::default().fg(theme.text_muted);
+        let (latency, latency_style) = match self.latency {
+            Some(ApiLatency::Measured(duration)) => {
+                (format!("{}ms", duration.as_millis()), theme.accent_style)
+            }
+            Some(ApiLatency::TimedOut) => ("timed out".to_string(), theme.error_style),
+            Some(ApiLatency::Failed) => ("failed".to_string(), theme.error_style),
+            None => ("-".to_string(), muted),
+        };
+
+        let websocket = &self.context.websocket;
+        let websocket_style = if websocket.is_connected() {
+            theme.accent_style
+        } else if websocket.is_enabled() {
+            theme.error_style
+        } else {
+            muted
+        };
+
+        Line::from(vec![
+            Span::styled("API latency: ", muted),
+            Span::styled(latency, latency_style),
+            Span::raw(format!("  {}  ", glyphs().bullet)),
+            Span::styled("WebSocket: ", muted),
+            Span::styled(websocket.label(), websocket_style),
+        ])
     }
 
     fn render_battery(battery: HttpModemBatteryLevelResponse, theme: &Theme) -> Vec<Line<'static>> {
-        let battery_level = battery.charge.min(100); // Ensure within 0-100 range
+        let battery_level = device_status::battery_percentage(battery);
 
-        let battery_top = "┌──────────────┐ ";
-        let battery_body1 = "│              │█";
-        let battery_body2 = "│              │█";
-        let battery_body3 = "│              │█";
-        let battery_bottom = "└──────────────┘ ";
+        let glyphs = glyphs();
+        let edge = glyphs.horizontal.repeat(14);
+        let battery_top = format!("{}{edge}{} ", glyphs.top_left, glyphs.top_right);
+        let battery_bottom = format!("{}{edge}{} ", glyphs.bottom_left, glyphs.bottom_right);
 
         let filled_chars = ((f32::from(battery_level) / 100.0) * 14.0) as usize;
-        let create_battery_line = |_outline: &str| -> Vec<Span<'static>> {
+        let create_battery_line = || -> Vec<Span<'static>> {
             let mut spans = Vec::new();
 
             // Left border
-            spans.push(Span::styled("│", Style::default().fg(theme.border)));
+            spans.push(Span::styled(
+                glyphs.vertical,
+                Style::default().fg(theme.border),
+            ));
 
             // Battery fill
             for i in 0..14 {
                 if i < filled_chars {
-                    let fill_color = match battery_level {
-                        81..=100 => Color::Green,
-                        61..=80 => tailwind::LIME.c400,
-                        41..=60 => Color::Yellow,
-                        21..=40 => tailwind::ORANGE.c400,
-                        _ => Color::Red,
-                    };
-                    spans.push(Span::styled("█", Style::default().fg(fill_color)));
+                    let fill_color = device_status::battery_color(battery_level);
+                    spans.push(Span::styled(glyphs.block, Style::default().fg(fill_color)));
                 } else {
                     spans.push(Span::raw(" "));
                 }
             }
 
             // Right border and terminal
-            spans.push(Span::styled("│", Style::default().fg(theme.border)));
-            spans.push(Span::styled("█", Style::default().fg(theme.border)));
+            spans.push(Span::styled(
+                glyphs.vertical,
+                Style::default().fg(theme.border),
+            ));
+            spans.push(Span::styled(
+                glyphs.block,
+                Style::default().fg(theme.border),
+            ));
 
             spans
         };
@@ -109,24 +156,28 @@ impl DeviceInfoView {
         let outline_style = Style::default().fg(theme.border);
 
         // Show charging indicator if charging
-        let status_indicator = if battery.status == 1 { "⚡ " } else { "" };
+        let status_indicator = if battery.status == 1 {
+            glyphs.charging
+        } else {
+            ""
+        };
 
         vec![
             Line::from(vec![Span::styled(battery_top, outline_style)]),
-            Line::from(create_battery_line(battery_body1)),
-            Line::from(create_battery_line(battery_body2)),
-            Line::from(create_battery_line(battery_body3)),
+            Line::from(create_battery_line()),
+            Line::from(create_battery_line()),
+            Line::from(create_battery_line()),
             Line::from(vec![Span::styled(battery_bottom, outline_style)]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 format!(
-                    "{}{}% • {:.2}V",
-                    status_indicator, battery_level, battery.voltage
+                    "{}{}% {} {:.2}V",
+                    status_indicator, battery_level, glyphs.bullet, battery.voltage
                 ),
                 theme.accent_style,
             )]),
             Line::from(vec![Span::styled(
-                Self::get_battery_status_text(battery),
+                device_status::battery_status_text(battery),
                 Style::default().fg(theme.text_muted),
             )]),
         ]
@@ -134,24 +185,14 @@ impl DeviceInfoView {
 
     fn render_signal_bars(
         signal: HttpModemSignalStrengthResponse,
+        show_details: bool,
         theme: &Theme,
     ) -> Vec<Line<'static>> {
-        let signal_rssi = if signal.rssi == 99 {
-            0
-        } else {
-            signal.rssi.min(31)
-        };
-        let signal_percentage = Self::get_signal_strength_percentage(signal);
-
-        // Convert RSSI to bars (0-5)
-        let bars = if signal_rssi == 0 {
-            0
-        } else {
-            ((signal_rssi as f32 / 31.0) * 5.0).ceil() as usize
-        };
+        let signal_percentage = device_status::signal_percentage(signal);
+        let bars = device_status::signal_bars(signal);
 
         let bar_heights = [1, 2, 3, 4, 5];
-        let (quality_text, signal_color) = Self::get_signal_quality_text(signal);
+        let (quality_text, signal_color) = device_status::signal_quality(signal);
         let filled_style = Style::default().fg(signal_color);
         let empty_style = Style::default().fg(theme.text_muted);
 
@@ -175,7 +216,7 @@ impl DeviceInfoView {
                     empty_style
                 };
 
-                spans.push(Span::styled("███", style));
+                spans.push(Span::styled(glyphs().block.repeat(3), style));
                 if bar_idx < bar_heights.len() - 1 {
                     spans.push(Span::raw(" "));
                 }
@@ -200,6 +241,9 @@ impl DeviceInfoView {
             )]));
         }
 
+        if !show_details {
+            return lines;
+        }
         lines.push(Line::from(vec![Span::styled(
             format!(
                 "{} dBm",
@@ -220,13 +264,7 @@ impl ViewBase for DeviceInfoView {
 
     async fn load(&mut self, _ctx: Self::Context<'_>) -> AppResult<()> {
         if self.device_info.is_none() {
-            self.device_info = Some(
-                self.context
-                    .0
-                    .get_device_info()
-                    .await
-                    .map_err(ClientError::from)?,
-            );
+            self.device_info = Some(self.fetch().await?);
         }
         Ok(())
     }
@@ -237,23 +275,24 @@ impl ViewBase for DeviceInfoView {
                 state: ViewStateRequest::default(),
                 dismiss_modal: false,
             }),
-            KeyCode::Char('r' | 'R') => match self.load(()).await {
-                Ok(()) => None,
-                Err(e) => Some(AppAction::SetViewState {
-                    state: ViewStateRequest::from(e),
-                    dismiss_modal: true,
-                }),
-            },
+            KeyCode::Char('r' | 'R') => {
+                self.refresh().await;
+                None
+            }
+            KeyCode::Char('t' | 'T') => {
+                self.toggle_details();
+                None
+            }
             _ => None,
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, _ctx: Self::Context<'_>) {
-        let area = centered_rect(60, 55, frame.area());
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, _ctx: Self::Context<'_>) {
+        let area = centered_rect(60, 55, area);
         frame.render_widget(Clear, area);
 
         let block = Block::bordered()
-            .title(" 📱 Device Information ")
+            .title(format!(" {}Device Information ", glyphs().device))
             .title_alignment(Alignment::Center)
             .border_type(BorderType::Rounded)
             .border_style(theme.border_focused_style);
@@ -272,9 +311,8 @@ impl ViewBase for DeviceInfoView {
             Constraint::Length(1),  // Spacing
             Constraint::Length(10), // Battery and Signal section
             Constraint::Length(1),  // Spacing
-            Constraint::Length(3),  // Network info and version
+            Constraint::Length(4),  // Network info, version and connection
             Constraint::Min(0),     // Flexible bottom spacing
-            Constraint::Length(1),  // Help text
         ])
         .split(inner);
 
@@ -282,11 +320,16 @@ impl ViewBase for DeviceInfoView {
         if let Some(ref phone_number) = device_info.phone_number {
             let phone_content = Paragraph::new(vec![
                 Line::from(vec![Span::styled(
-                    "📞 Phone Number",
+                    format!("{}Phone Number", glyphs().phone),
                     theme.secondary_style.add_modifier(Modifier::BOLD),
                 )]),
                 Line::from(vec![Span::styled(
-                    format!("╰─── {phone_number} ───╯"),
+                    format!(
+                        "{}{line} {phone_number} {line}{}",
+                        glyphs().rounded_bottom_left,
+                        glyphs().rounded_bottom_right,
+                        line = glyphs().horizontal.repeat(3)
+                    ),
                     theme.accent_style.add_modifier(Modifier::BOLD),
                 )]),
             ])
@@ -323,7 +366,7 @@ impl ViewBase for DeviceInfoView {
             ])
             .split(battery_center[1]);
 
-            let battery_title = Paragraph::new("🔋 Battery")
+            let battery_title = Paragraph::new(format!("{}Battery", glyphs().battery))
                 .style(theme.secondary_style.add_modifier(Modifier::BOLD))
                 .alignment(Alignment::Center);
             frame.render_widget(battery_title, battery_content[0]);
@@ -349,13 +392,14 @@ impl ViewBase for DeviceInfoView {
             ])
             .split(signal_center[1]);
 
-            let signal_title = Paragraph::new("📶 Signal")
+            let signal_title = Paragraph::new(format!("{}Signal", glyphs().signal))
                 .style(theme.secondary_style.add_modifier(Modifier::BOLD))
                 .alignment(Alignment::Center);
             frame.render_widget(signal_title, signal_content[0]);
 
-            let signal_visual = Paragraph::new(Self::render_signal_bars(signal, theme))
-                .alignment(Alignment::Center);
+            let signal_visual =
+                Paragraph::new(Self::render_signal_bars(signal, self.show_details, theme))
+                    .alignment(Alignment::Center);
             frame.render_widget(signal_visual, signal_content[2]);
         }
 
@@ -373,7 +417,7 @@ impl ViewBase for DeviceInfoView {
         ])];
 
         // Add technical details
-        if let Some(ref signal) = device_info.signal {
+        if let Some(signal) = device_info.signal.as_ref().filter(|_| self.show_details) {
             let ber_text = if signal.ber == 99 {
                 "Unknown".to_string()
             } else if signal.ber <= 7 {
@@ -385,7 +429,7 @@ impl ViewBase for DeviceInfoView {
             network_lines.push(Line::from(vec![
                 Span::styled("BER: ", Style::default().fg(theme.text_muted)),
                 Span::styled(ber_text, theme.accent_style),
-                Span::raw("  •  "),
+                Span::raw(format!("  {}  ", glyphs().bullet)),
                 Span::styled("Raw RSSI: ", Style::default().fg(theme.text_muted)),
                 Span::styled(
                     if signal.rssi == 99 {
@@ -404,13 +448,22 @@ impl ViewBase for DeviceInfoView {
             Span::styled(&device_info.version, theme.accent_style),
         ]));
 
+        network_lines.push(self.render_connection_line(theme));
+
         let network_info = Paragraph::new(network_lines).alignment(Alignment::Center);
         frame.render_widget(network_info, main_layout[5]);
+    }
 
-        // Help text
-        let help = Paragraph::new("(r) refresh, (Esc) menu")
-            .style(Style::default().fg(theme.text_muted))
-            .alignment(Alignment::Center);
-        frame.render_widget(help, main_layout[7]);
+    fn key_hints(&self, _ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        let details = if self.show_details {
+            "hide details"
+        } else {
+            "show details"
+        };
+        vec![
+            ("r".into(), "refresh"),
+            ("t".into(), details),
+            ("Esc".into(), "menu"),
+        ]
     }
 }
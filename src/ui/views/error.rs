@@ -1,6 +1,5 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use ratatui::layout::{Alignment, Constraint, Layout};
-use ratatui::style::Style;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::widgets::{Block, BorderType, Clear, Paragraph, Wrap};
 use ratatui::Frame;
 
@@ -8,7 +7,7 @@ use crate::error::AppResult;
 use crate::theme::Theme;
 use crate::types::AppAction;
 use crate::ui::views::ViewStateRequest;
-use crate::ui::{centered_rect, ViewBase};
+use crate::ui::{centered_rect, KeyHint, ViewBase};
 
 pub struct ErrorView;
 impl ErrorView {
@@ -17,7 +16,7 @@ impl ErrorView {
     }
 }
 impl ViewBase for ErrorView {
-    type Context<'ctx> = (&'ctx String, bool);
+    type Context<'ctx> = (&'ctx String, bool, Option<&'ctx ViewStateRequest>);
 
     async fn load(&mut self, _ctx: Self::Context<'_>) -> AppResult<()> {
         Ok(())
@@ -29,6 +28,10 @@ impl ViewBase for ErrorView {
                 state: ViewStateRequest::default(),
                 dismiss_modal: false,
             }),
+            KeyCode::Char('r' | 'R') => ctx.2.map(|request| AppAction::SetViewState {
+                state: request.clone(),
+                dismiss_modal: false,
+            }),
             KeyCode::Char('c' | 'C') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 Some(AppAction::Exit)
             }
@@ -36,8 +39,8 @@ impl ViewBase for ErrorView {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, ctx: Self::Context<'_>) {
-        let area = centered_rect(60, 25, frame.area());
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, ctx: Self::Context<'_>) {
+        let area = centered_rect(60, 25, area);
         frame.render_widget(Clear, area);
 
         let block = Block::bordered()
@@ -53,7 +56,6 @@ impl ViewBase for ErrorView {
             Constraint::Length(1), // Spacing
             Constraint::Min(3),    // Error message
             Constraint::Length(1), // Spacing
-            Constraint::Length(1), // Control hints
         ])
         .split(inner);
 
@@ -63,16 +65,17 @@ impl ViewBase for ErrorView {
             .wrap(Wrap { trim: true })
             .alignment(Alignment::Center);
         frame.render_widget(error_text, layout[1]);
+    }
 
-        // Control hints
-        let help_text = if ctx.1 {
-            "(Esc) dismiss, (Ctrl+C) quit"
-        } else {
-            "(Ctrl+C) quit"
-        };
-        let help = Paragraph::new(help_text)
-            .style(Style::default().fg(theme.text_muted))
-            .alignment(Alignment::Center);
-        frame.render_widget(help, layout[3]);
+    fn key_hints(&self, ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        let mut hints = Vec::new();
+        if ctx.2.is_some() {
+            hints.push(("r".into(), "retry"));
+        }
+        if ctx.1 {
+            hints.push(("Esc".into(), "dismiss"));
+        }
+        hints.push(("Ctrl+C".into(), "quit"));
+        hints
     }
 }
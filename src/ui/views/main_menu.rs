@@ -5,10 +5,11 @@ use ratatui::widgets::{Block, BorderType, Clear, Paragraph};
 use ratatui::Frame;
 
 use crate::error::AppResult;
+use crate::glyphs::glyphs;
 use crate::theme::Theme;
 use crate::types::AppAction;
 use crate::ui::views::ViewStateRequest;
-use crate::ui::{centered_rect, ViewBase};
+use crate::ui::{centered_rect, vim_navigation_key, KeyHint, ViewBase};
 
 pub struct MenuItem {
     pub label: String,
@@ -49,6 +50,7 @@ impl MenuItem {
 pub struct MainMenuView {
     menu_items: Vec<MenuItem>,
     selected_index: usize,
+    vim_keys: bool,
 }
 impl MainMenuView {
     pub fn new() -> Self {
@@ -65,15 +67,28 @@ impl MainMenuView {
                 ViewStateRequest::DeviceInfo,
                 "D",
             ),
+            MenuItem::new(
+                "About",
+                "Version, server and feature information for bug reports",
+                || AppAction::ShowAbout,
+                "A",
+            ),
             MenuItem::new("Exit", "Close the terminal", || AppAction::Exit, "Q"),
         ];
 
         Self {
             menu_items,
             selected_index: 0,
+            vim_keys: false,
         }
     }
 
+    /// Also move the selection with j/k, none of the shortcuts use them.
+    pub fn with_vim_keys(mut self, vim_keys: bool) -> Self {
+        self.vim_keys = vim_keys;
+        self
+    }
+
     fn select_next(&mut self) {
         if !self.menu_items.is_empty() {
             self.selected_index = (self.selected_index + 1) % self.menu_items.len();
@@ -116,6 +131,12 @@ impl ViewBase for MainMenuView {
     }
 
     async fn handle_key(&mut self, key: KeyEvent, _ctx: Self::Context<'_>) -> Option<AppAction> {
+        let key = if self.vim_keys {
+            vim_navigation_key(key)
+        } else {
+            key
+        };
+
         match key.code {
             KeyCode::Char('c' | 'C') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 Some(AppAction::Exit)
@@ -137,8 +158,8 @@ impl ViewBase for MainMenuView {
         }
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, _ctx: Self::Context<'_>) {
-        let area = centered_rect(60, 50, frame.area());
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, _ctx: Self::Context<'_>) {
+        let area = centered_rect(60, 50, area);
         frame.render_widget(Clear, area);
 
         // Main container
@@ -158,9 +179,6 @@ impl ViewBase for MainMenuView {
             Constraint::Length(1), // Welcome text
             Constraint::Length(2), // Spacing after welcome
             Constraint::Min(0),    // Menu items (flexible)
-            Constraint::Length(2), // Spacing before help
-            Constraint::Length(1), // Theme controls hint
-            Constraint::Length(1), // Help text
             Constraint::Length(1), // Bottom spacing
         ])
         .split(inner);
@@ -248,33 +266,25 @@ impl ViewBase for MainMenuView {
             // Visual separator between items (except for the last one)
             if i < self.menu_items.len() - 1 {
                 let separator_width = (item_rect.width as usize).saturating_sub(8);
-                let separator_text = "─".repeat(separator_width);
+                let separator_text = glyphs().horizontal.repeat(separator_width);
                 let separator = Paragraph::new(separator_text)
                     .style(Style::default().fg(theme.text_muted))
                     .alignment(Alignment::Center);
                 frame.render_widget(separator, item_layout[3]);
             }
         }
+    }
 
-        // Controls hint
-        let help_text = "↑↓ navigate, (Enter) select, (Ctrl+C) to quit";
-        let help = Paragraph::new(help_text)
-            .style(
-                Style::default()
-                    .fg(theme.text_muted)
-                    .add_modifier(Modifier::ITALIC),
-            )
-            .alignment(Alignment::Center);
-        frame.render_widget(help, layout[5]);
-
-        // Theme hint
-        let theme_hint = Paragraph::new("(F10) change theme color, (F11) toggle background fill")
-            .style(
-                Style::default()
-                    .fg(theme.text_muted)
-                    .add_modifier(Modifier::ITALIC),
-            )
-            .alignment(Alignment::Center);
-        frame.render_widget(theme_hint, layout[6]);
+    fn key_hints(&self, _ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        let vim = if self.vim_keys { " j/k" } else { "" };
+        vec![
+            (format!("{}{vim}", glyphs().keys_up_down), "navigate"),
+            ("Enter".into(), "select"),
+            ("Ctrl+G".into(), "go to number"),
+            ("F1".into(), "about"),
+            ("F10".into(), "theme"),
+            ("F11".into(), "background"),
+            ("Ctrl+C".into(), "quit"),
+        ]
     }
 }
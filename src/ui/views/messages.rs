@@ -1,41 +1,142 @@
 use crate::app::AppContext;
+use crate::clipboard;
 use crate::error::{AppError, AppResult};
-use crate::modals::AppModal;
+use crate::glyphs::glyphs;
+use crate::links;
+use crate::modals::{AppModal, ModalMetadata, ModalResponse};
+use crate::sanitize::sanitize_text;
 use crate::theme::Theme;
-use crate::types::AppAction;
-use crate::ui::modals::delivery_reports::DeliveryReportsModal;
+use crate::timestamps::{DisplayTimezone, TimestampFormatter};
+use crate::types::{AppAction, ContentNewlines, MessageLabel};
+use crate::ui::modals::delivery_reports::{status_indicator, DeliveryReportsModal};
+use crate::ui::modals::json_inspect::JsonInspectModal;
+use crate::ui::modals::list_select::ListSelectModal;
+use crate::ui::modals::text_input::TextInputModal;
+use crate::ui::notifications::NotificationType;
 use crate::ui::views::ViewStateRequest;
-use crate::ui::ViewBase;
-use ansi_escape_sequences::strip_ansi;
-use chrono::{Local, TimeZone};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use ratatui::layout::{Constraint, Layout, Margin, Rect};
-use ratatui::style::{Modifier, Style, Stylize};
-use ratatui::text::{Line, Text};
+use crate::ui::{vim_navigation_key, KeyHint, ModalResponderComponent, ViewBase};
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Constraint, Layout, Margin, Position, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{
     Block, BorderType, Cell, HighlightSpacing, Paragraph, Row, Scrollbar, ScrollbarOrientation,
     ScrollbarState, Table, TableState,
 };
 use ratatui::Frame;
+use sms_client::http::HttpClient;
 use sms_client::types::http::HttpPaginationOptions;
-use sms_client::types::sms::SmsMessage;
-use unicode_general_category::{get_general_category, GeneralCategory};
+use sms_client::types::sms::{SmsDeliveryReportStatusCategory, SmsMessage};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use tokio::task::JoinSet;
 use unicode_width::UnicodeWidthStr;
 
-const ITEM_HEIGHT: usize = 4;
+const DEFAULT_ITEM_HEIGHT: usize = 4;
+const MULTILINE_ITEM_HEIGHT: usize = 6; // Room for a few kept newlines
 const LOAD_THRESHOLD: usize = 5;
 const MESSAGES_PER_PAGE: u64 = 20;
+const SCROLL_ROWS: usize = 3;
+const MAX_UNREAD_PAGES: usize = 5;
+const MAX_SEARCH_PAGES: usize = 10;
+const MAX_RESTORE_PAGES: usize = 10;
+const MAX_STATUS_REQUESTS: usize = 4; // Concurrent delivery report requests per page
+const STATUS_REPORTS_LIMIT: u64 = 10; // Reports fetched per message to find the latest
+const COLUMNS: [&str; 4] = ["ID", "Dir", "Time", "Content"];
+const REMOVE_LABEL: &str = "(remove label)";
+
+static NEXT_PENDING_ID: AtomicI64 = AtomicI64::new(-1);
+
+/// A message ID for an optimistic row, negative so it can't clash with the server's.
+pub fn next_pending_id() -> i64 {
+    NEXT_PENDING_ID.fetch_sub(1, AtomicOrdering::Relaxed)
+}
+
+/// Unique across views, so a page can't be mistaken for one requested by another view.
+static NEXT_LOAD_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// A page of messages with the delivery statuses of its outgoing ones.
+type PageContents = (
+    Vec<SmsMessage>,
+    HashMap<i64, SmsDeliveryReportStatusCategory>,
+);
+
+/// The page load in flight, if any. Each load gets a new generation, and its result is only
+/// accepted while that load is still the one in flight, so a response for a load superseded
+/// by a reset is dropped instead of clobbering fresh state.
+#[derive(Debug, Default)]
+struct LoadTracker {
+    in_flight: Option<u64>,
+}
+impl LoadTracker {
+    /// Start a load, returning its generation, or `None` if one is already in flight.
+    fn start(&mut self) -> Option<u64> {
+        if self.in_flight.is_some() {
+            return None;
+        }
+        let generation = NEXT_LOAD_GENERATION.fetch_add(1, AtomicOrdering::Relaxed);
+        self.in_flight = Some(generation);
+        Some(generation)
+    }
+
+    /// Finish a load, returning whether its result is current and should be applied.
+    fn finish(&mut self, generation: u64) -> bool {
+        if self.in_flight != Some(generation) {
+            return false;
+        }
+        self.in_flight = None;
+        true
+    }
+
+    /// Forget the load in flight, so its result is dropped when it arrives.
+    fn cancel(&mut self) {
+        self.in_flight = None;
+    }
+
+    fn is_loading(&self) -> bool {
+        self.in_flight.is_some()
+    }
+}
+
+/// Which part of the conversation a background page load is for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PageKind {
+    Next { matched: usize, pages: usize }, // Loaded when a search round began, pages fetched
+    Evicted { offset: u64 },               // The evicted messages from here to `window_start`
+}
+
+/// A page loaded in the background, handed back to the view through
+/// `AppAction::MessagesPage` so scrolling doesn't wait on the server.
+#[derive(Debug, PartialEq)]
+pub struct MessagesPage {
+    pub phone_number: String,
+    generation: u64,
+    kind: PageKind,
+    result: Result<PageContents, ViewStateRequest>,
+}
+
+/// An outgoing message shown before the server has stored it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PendingSend {
+    failed: bool,
+    created_at: i64,
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct SmsMessageTableRecord {
     pub phone_number: String,
-    pub identifier: String,
-    pub direction: &'static str,
+    pub identifier: String, // Display only, match on message_id instead
+    pub direction: String,
     pub timestamp: String,
     pub content: String,
     pub is_outgoing: bool,
     pub message_id: i64,
-    original_message: Option<SmsMessage>,
+    pub delivery_status: Option<SmsDeliveryReportStatusCategory>, // None if no reports yet
+    original_message: Option<SmsMessage>,                         // None while pending
+    pending: Option<PendingSend>,
 }
 
 impl SmsMessageTableRecord {
@@ -43,103 +144,620 @@ impl SmsMessageTableRecord {
     pub fn ref_array(&self) -> [&str; 4] {
         [
             &self.identifier,
-            self.direction,
+            &self.direction,
             &self.timestamp,
             &self.content,
         ]
     }
-}
-impl From<SmsMessage> for SmsMessageTableRecord {
-    fn from(value: SmsMessage) -> Self {
+
+    pub fn new(
+        value: SmsMessage,
+        timestamps: &TimestampFormatter,
+        newlines: ContentNewlines,
+        delivery_status: Option<SmsDeliveryReportStatusCategory>,
+    ) -> Self {
         let dt = value
             .completed_at
             .or(value.created_at)
-            .and_then(|t| Local.timestamp_opt(i64::from(t), 0).single())
-            .unwrap_or_else(Local::now);
+            .and_then(DisplayTimezone::from_unix)
+            .unwrap_or_else(Utc::now);
 
         let message_id = value.message_id.expect("SmsMessage missing message_id");
         let is_outgoing = value.is_outgoing;
 
-        // Pre-allocate with estimated capacity for content filtering
-        let stripped = strip_ansi(&value.message_content);
-        let mut content = String::with_capacity(stripped.len());
-        content.extend(stripped.chars().filter(|c| {
-            !c.is_control()
-                && !matches!(
-                    get_general_category(*c),
-                    GeneralCategory::Format
-                        | GeneralCategory::Control
-                        | GeneralCategory::Unassigned
-                )
-        }));
+        let content = sanitize_text(&value.message_content, newlines);
 
         Self {
             phone_number: value.phone_number.clone(),
             identifier: message_id.to_string(),
-            direction: if is_outgoing { "← OUT" } else { "→ IN" },
-            timestamp: dt.format("%d/%m/%y %H:%M").to_string(),
+            direction: if is_outgoing {
+                format!("{} OUT", glyphs().arrow_left)
+            } else {
+                format!("{} IN", glyphs().arrow_right)
+            },
+            timestamp: timestamps.date_time(dt),
             content,
             is_outgoing,
             message_id,
-            // Only store original if outgoing (needed for delivery reports)
-            original_message: if is_outgoing { Some(value) } else { None },
+            delivery_status,
+            // Kept for delivery reports and the debug inspector
+            original_message: Some(value),
+            pending: None,
+        }
+    }
+
+    /// An optimistic row for a message that is still being sent.
+    pub fn pending(
+        pending_id: i64,
+        phone_number: String,
+        content: &str,
+        parts: usize,
+        newlines: ContentNewlines,
+    ) -> Self {
+        Self {
+            phone_number,
+            identifier: "-".to_string(),
+            direction: format!("{} OUT", glyphs().arrow_left),
+            timestamp: if parts > 1 {
+                format!("Sending {parts} parts...")
+            } else {
+                "Sending...".to_string()
+            },
+            content: sanitize_text(content, newlines),
+            is_outgoing: true,
+            message_id: pending_id,
+            delivery_status: None,
+            original_message: None,
+            pending: Some(PendingSend {
+                failed: false,
+                created_at: Utc::now().timestamp(),
+            }),
+        }
+    }
+
+    /// Unix time the message was sent or received at, if known.
+    pub fn unix_time(&self) -> Option<i64> {
+        match &self.original_message {
+            Some(message) => message.completed_at.or(message.created_at).map(i64::from),
+            None => self.pending.map(|pending| pending.created_at),
+        }
+    }
+
+    fn is_unread(&self, last_seen: i64) -> bool {
+        self.unix_time().is_some_and(|time| time > last_seen)
+    }
+
+    /// Order two records by one of the table's columns.
+    fn compare_by_column(&self, other: &Self, column: usize) -> Ordering {
+        match column {
+            0 => self.message_id.cmp(&other.message_id),
+            1 => self.is_outgoing.cmp(&other.is_outgoing),
+            2 => self.unix_time().cmp(&other.unix_time()),
+            _ => self.content.cmp(&other.content),
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum DirectionFilter {
+    #[default]
+    All,
+    Incoming,
+    Outgoing,
+}
+impl DirectionFilter {
+    fn next(self) -> Self {
+        match self {
+            Self::All => Self::Incoming,
+            Self::Incoming => Self::Outgoing,
+            Self::Outgoing => Self::All,
+        }
+    }
+
+    fn matches(self, record: &SmsMessageTableRecord) -> bool {
+        match self {
+            Self::All => true,
+            Self::Incoming => !record.is_outgoing,
+            Self::Outgoing => record.is_outgoing,
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Self::All => "All".to_string(),
+            Self::Incoming => format!("{} Incoming", glyphs().arrow_right),
+            Self::Outgoing => format!("{} Outgoing", glyphs().arrow_left),
+        }
+    }
+}
+
+/// A divider like "── New messages ──" drawn above the first unread message.
+fn unread_marker_text(text: &str) -> String {
+    let line = glyphs().horizontal.repeat(2);
+    format!("{line} {text} {line}")
+}
+
+/// Where a conversation was left, so it can be resumed when opened again this session.
+/// Only the selection is kept, and enough pages are re-fetched to reach it, so new messages
+/// and delivery statuses are picked up rather than shown stale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessagesPosition {
+    message_id: i64, // The selected message
+    loaded: usize,   // How many messages were loaded
+    reversed: bool,  // Positions don't carry over to the other order
+}
+
 pub struct MessagesView {
     context: AppContext,
     state: TableState,
     messages: Vec<SmsMessageTableRecord>,
     longest_item_lens: (u16, u16, u16, u16),
     scroll_state: ScrollbarState,
-    is_loading: bool,
+    loads: LoadTracker,
     has_more: bool,
     reversed: bool,
     current_offset: u64,
+    window_start: u64, // Server offset of the first loaded message, past any evicted ones
+    evicted_newest_id: Option<i64>, // Newest first, the newest message evicted from the top
     total_messages: usize,
     is_selected_outgoing: bool,
+    filter: DirectionFilter,
+    item_height: usize, // Terminal rows per message, for the scrollbar and mouse hit-testing
+    selected_column: Option<usize>, // Index into COLUMNS, mirrored into the TableState
+    sort: Option<(usize, bool)>, // Client side sort of loaded messages: column, descending
+    search: Option<String>, // Only keep messages containing this, paging through history
+    unread_since: Option<i64>, // When the conversation was last seen, as of opening it
+    restore: Option<MessagesPosition>, // Where to resume once loaded
+    table_area: Rect,   // Last rendered, for mouse hit-testing
 }
 impl MessagesView {
     pub fn with_context(context: AppContext) -> Self {
+        let item_height = Self::item_height(context.options.content_newlines);
         Self {
             context,
             state: TableState::default(),
             messages: Vec::new(),
             longest_item_lens: (10, 10, 20, 50),
             scroll_state: ScrollbarState::new(0),
-            is_loading: false,
+            loads: LoadTracker::default(),
             has_more: true,
             reversed: false,
             current_offset: 0,
+            window_start: 0,
+            evicted_newest_id: None,
             total_messages: 0,
             is_selected_outgoing: false,
+            filter: DirectionFilter::default(),
+            item_height,
+            selected_column: None,
+            sort: None,
+            search: None,
+            unread_since: None,
+            restore: None,
+            table_area: Rect::default(),
+        }
+    }
+
+    /// Search the conversation's history for messages containing a query (case-insensitive).
+    /// The server has no search endpoint, so pages are fetched and filtered client side.
+    pub fn with_search(mut self, query: Option<String>) -> Self {
+        self.search = query;
+        self
+    }
+
+    pub fn search(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
+
+    /// Resume at a previous position once loaded, if it's for the same order.
+    pub fn set_restore_position(&mut self, position: Option<MessagesPosition>) {
+        self.restore = position;
+    }
+
+    /// The current position, to resume from later. Searches aren't remembered.
+    pub fn position(&self) -> Option<MessagesPosition> {
+        if self.search.is_some() {
+            return None;
+        }
+        Some(MessagesPosition {
+            message_id: self.selected_message()?.message_id,
+            loaded: self.messages.len(),
+            reversed: self.reversed,
+        })
+    }
+
+    /// Bring a cached view up to date when it's shown again, instead of loading it from
+    /// scratch. The newest page is fetched and merged, keeping the selection, unless more
+    /// messages arrived than fit in it.
+    pub async fn resume(&mut self, phone_number: &str) -> AppResult<()> {
+        // A page in flight when the view was cached was dropped, as it wasn't current.
+        self.loads.cancel();
+        self.unread_since = self.context.state.last_seen(phone_number);
+        let pagination = HttpPaginationOptions::default().with_limit(MESSAGES_PER_PAGE);
+        let messages = self
+            .context
+            .http
+            .get_messages(phone_number, Some(pagination))
+            .await
+            .map_err(AppError::from)?;
+        self.merge_newest_page(phone_number, messages, MESSAGES_PER_PAGE)
+            .await?;
+        self.mark_seen();
+        Ok(())
+    }
+
+    /// Merge a page of the newest messages (given newest first, fetched with `limit`). A
+    /// full page that doesn't reach back to any known message may have more new messages
+    /// behind it, which merging would skip, so the conversation is reloaded instead and
+    /// the selection restored if it's still within reach.
+    pub async fn merge_newest_page(
+        &mut self,
+        phone_number: &str,
+        messages: Vec<SmsMessage>,
+        limit: u64,
+    ) -> AppResult<()> {
+        if self.reaches_known(&messages, limit) {
+            self.merge_newest(messages);
+            return Ok(());
+        }
+
+        let position = self.position();
+        self.reload(phone_number).await?;
+        if let Some(position) = position {
+            self.restore_position(phone_number, position).await?;
+        }
+        Ok(())
+    }
+
+    /// Whether a page of the newest messages leaves no gap before those already known
+    /// (loaded or evicted): it's short, so holds every message there is, or overlaps them.
+    /// Oldest first without the newest loaded, merging is skipped so there's no gap either.
+    fn reaches_known(&self, messages: &[SmsMessage], limit: u64) -> bool {
+        if (messages.len() as u64) < limit || (self.reversed && self.has_more) {
+            return true;
+        }
+
+        // Pending rows have negative IDs and aren't stored yet.
+        let Some(newest_known) = self
+            .messages
+            .iter()
+            .map(|m| m.message_id)
+            .filter(|id| *id >= 0)
+            .chain(self.evicted_newest_id)
+            .max()
+        else {
+            return false;
+        };
+        messages
+            .iter()
+            .filter_map(|message| message.message_id)
+            .any(|id| id <= newest_known)
+    }
+
+    /// Load pages until the remembered message is loaded (up to a limit), then select it.
+    /// Returns false if it couldn't be found, eg: it was deleted or is too far back.
+    async fn restore_position(
+        &mut self,
+        phone_number: &str,
+        position: MessagesPosition,
+    ) -> AppResult<bool> {
+        if position.reversed != self.reversed {
+            return Ok(false);
+        }
+
+        let mut pages = 1;
+        while !self.is_loaded(position.message_id)
+            && self.has_more
+            && self.messages.len() < position.loaded
+            && pages < MAX_RESTORE_PAGES
+        {
+            self.load_messages(phone_number).await?;
+            pages += 1;
+        }
+
+        let Some(idx) = self
+            .visible()
+            .position(|m| m.message_id == position.message_id)
+        else {
+            return Ok(false);
+        };
+        self.select_row(idx);
+        Ok(true)
+    }
+
+    /// Is a message already loaded, by its server ID.
+    fn is_loaded(&self, message_id: i64) -> bool {
+        self.messages.iter().any(|m| m.message_id == message_id)
+    }
+
+    /// The conversation's friendly name with its number, eg: "Alice (+44123)", or just the
+    /// number if it has no name. Sanitized, as names are set by other clients too.
+    pub fn display_name(&self, phone_number: &str) -> String {
+        let name = match self.context.contacts.friendly_name(phone_number) {
+            Some(name) => format!("{name} ({phone_number})"),
+            None => phone_number.to_string(),
+        };
+        sanitize_text(&name, ContentNewlines::Strip)
+    }
+
+    /// Keep only the records matching the search query, if there is one.
+    fn retain_search_matches(&self, records: &mut Vec<SmsMessageTableRecord>) {
+        if let Some(query) = &self.search {
+            let query = query.to_lowercase();
+            records.retain(|record| record.content.to_lowercase().contains(&query));
         }
     }
 
     /// Add a live message, taking ownership to avoid cloning
     pub fn add_live_message(&mut self, message: SmsMessage) {
-        let message_id = message.message_id.expect("SmsMessage missing message_id");
+        self.merge_newest(vec![message]);
+    }
+
+    /// Show a message that is being sent as the newest, until `resolve_pending` is called.
+    /// It's skipped if the newest messages aren't loaded, as it would be out of place.
+    pub fn add_pending(
+        &mut self,
+        pending_id: i64,
+        phone_number: &str,
+        content: &str,
+        parts: usize,
+    ) {
+        if (self.reversed && self.has_more) || (!self.reversed && self.window_start > 0) {
+            return;
+        }
+        let record = SmsMessageTableRecord::pending(
+            pending_id,
+            phone_number.to_string(),
+            content,
+            parts,
+            self.context.options.content_newlines,
+        );
+        self.insert_newest(vec![record], false);
+    }
+
+    /// Replace a pending row once its send finished. On success the stored message has
+    /// already been merged in (with the row's selection moving to it), on failure the row
+    /// stays and is marked as failed.
+    pub fn resolve_pending(&mut self, pending_id: i64, message_id: Option<i64>) {
+        let Some(idx) = self
+            .messages
+            .iter()
+            .position(|m| m.message_id == pending_id)
+        else {
+            return;
+        };
+
+        let Some(message_id) = message_id else {
+            let record = &mut self.messages[idx];
+            record.timestamp = "Failed".to_string();
+            if let Some(pending) = &mut record.pending {
+                pending.failed = true;
+            }
+            return;
+        };
+
+        let selected_id = self.selected_message().map(|m| m.message_id).map(|id| {
+            if id == pending_id {
+                message_id
+            } else {
+                id
+            }
+        });
+        let removed = self.messages.remove(idx);
+        if !self.reversed && idx < self.state.offset() && self.filter.matches(&removed) {
+            *self.state.offset_mut() -= 1;
+        }
+        self.reselect(selected_id);
+        self.total_messages = self.messages.len();
+        self.update_constraints();
+        self.update_scroll_state();
+    }
+
+    /// Merge messages newer than those loaded (given newest first), skipping any already
+    /// present. Unlike the initial load this keeps the selected message and scroll position.
+    pub fn merge_newest(&mut self, new_messages: Vec<SmsMessage>) {
+        // Oldest first with more pages to load means the newest aren't loaded yet,
+        // so these will arrive in order through pagination instead.
+        if self.reversed && self.has_more {
+            return;
+        }
 
-        // Check for duplicates before converting
-        if self.messages.iter().any(|m| m.message_id == message_id) {
+        // Newest first with the top evicted, these belong above what is loaded and will
+        // be fetched when scrolling back up. Only the offsets need to move past them.
+        if !self.reversed && self.window_start > 0 {
+            let newest_evicted = self.evicted_newest_id.unwrap_or(i64::MAX);
+            let newer: Vec<i64> = new_messages
+                .iter()
+                .filter_map(|message| message.message_id)
+                .filter(|id| *id > newest_evicted)
+                .collect();
+            if !newer.is_empty() {
+                // A page in flight was requested at the old offsets.
+                self.loads.cancel();
+            }
+            self.window_start += newer.len() as u64;
+            self.current_offset += newer.len() as u64;
+            self.evicted_newest_id = newer.into_iter().max().or(self.evicted_newest_id);
             return;
         }
 
-        let record = SmsMessageTableRecord::from(message);
-        self.messages.insert(0, record);
+        let (timestamps, newlines) = (
+            &self.context.options.timestamps,
+            self.context.options.content_newlines,
+        );
+        let records: Vec<SmsMessageTableRecord> = new_messages
+            .into_iter()
+            .filter(|message| message.message_id.is_some_and(|id| !self.is_loaded(id)))
+            .map(|message| SmsMessageTableRecord::new(message, timestamps, newlines, None))
+            .collect();
+        self.insert_newest(records, true);
+    }
+
+    /// Insert records (newest first) at the newest end of those loaded. Pending rows aren't
+    /// stored on the server, so only stored ones move the pagination offset. They're counted
+    /// before the search filter, since skipped matches still shift the server's offsets.
+    fn insert_newest(&mut self, mut records: Vec<SmsMessageTableRecord>, stored: bool) {
+        if stored && !self.reversed && !records.is_empty() {
+            // A page in flight was requested at the old offset.
+            self.loads.cancel();
+            self.current_offset += records.len() as u64;
+        }
+        self.retain_search_matches(&mut records);
+        if records.is_empty() {
+            return;
+        }
+
+        let selected_id = self.selected_message().map(|m| m.message_id);
+        if self.reversed {
+            records.reverse();
+            self.messages.extend(records);
+        } else {
+            // Shift the viewport down by what was inserted above it.
+            let inserted_visible = records.iter().filter(|m| self.filter.matches(m)).count();
+            *self.state.offset_mut() += inserted_visible;
+
+            records.append(&mut self.messages);
+            self.messages = records;
+        }
+        self.sort_loaded();
+
+        let selected = match selected_id {
+            Some(id) => self.visible().position(|m| m.message_id == id),
+            None => (self.visible_len() > 0).then_some(0),
+        };
+        self.state.select(selected);
+        self.update_selection(selected.unwrap_or(0));
+
         self.total_messages = self.messages.len();
         self.update_constraints();
-        self.scroll_state =
-            ScrollbarState::new(self.messages.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.update_scroll_state();
+        self.mark_seen();
+    }
+
+    /// The newest message, if it has been loaded (oldest first only loads it on the last page).
+    fn newest_loaded(&self) -> Option<&SmsMessageTableRecord> {
+        if !self.reversed {
+            self.messages.first()
+        } else if !self.has_more {
+            self.messages.last()
+        } else {
+            None
+        }
+    }
+
+    /// Remember the conversation as seen up to the newest loaded message.
+    fn mark_seen(&self) {
+        // Search results skip messages, so the newest result isn't the newest message.
+        if self.search.is_some() {
+            return;
+        }
+        if let Some(newest) = self.newest_loaded() {
+            if let Some(time) = newest.unix_time() {
+                self.context.state.set_last_seen(&newest.phone_number, time);
+            }
+        }
+    }
+
+    /// Where to draw the new messages marker, as the message ID whose row it's drawn at the
+    /// top of and its text. Newest first, it's on the first seen message, pointing up at the
+    /// new ones. Oldest first, it's on the first new message. If every loaded message is new
+    /// it's on the top row, but only once the conversation's start is loaded so the boundary
+    /// is known. Sorting and searching reorder or skip messages, so there's no marker then.
+    fn unread_marker(&self) -> Option<(i64, String)> {
+        let since = self.unread_since?;
+        if self.sort.is_some() || self.search.is_some() {
+            return None;
+        }
+
+        if self.reversed {
+            let first_unread = self.messages.iter().position(|m| m.is_unread(since))?;
+            (first_unread > 0 || self.window_start == 0).then(|| {
+                (
+                    self.messages[first_unread].message_id,
+                    unread_marker_text("New messages"),
+                )
+            })
+        } else {
+            let unread = self
+                .messages
+                .iter()
+                .take_while(|m| m.is_unread(since))
+                .count();
+            if unread == 0 {
+                return None;
+            }
+            match self.messages.get(unread) {
+                Some(first_seen) => Some((
+                    first_seen.message_id,
+                    unread_marker_text(&format!("New messages {}", glyphs().arrow_up)),
+                )),
+                None => (!self.has_more).then(|| {
+                    (
+                        self.messages[0].message_id,
+                        unread_marker_text("New messages"),
+                    )
+                }),
+            }
+        }
+    }
+
+    /// Select the oldest message newer than when the conversation was last seen, loading a
+    /// few more pages if it isn't in the first. Without any unread messages, select the newest.
+    async fn select_first_unread(&mut self, phone_number: &str) -> AppResult<()> {
+        let Some(last_seen) = self.context.state.last_seen(phone_number) else {
+            return Ok(());
+        };
+        if self.search.is_some() {
+            return Ok(());
+        }
+
+        // Newest first, the first unread is found once a read message is loaded below it.
+        // Oldest first, it's found once any unread message has been loaded.
+        let mut pages = 1;
+        while self.has_more && pages < MAX_UNREAD_PAGES {
+            let found = if self.reversed {
+                self.messages.iter().any(|m| m.is_unread(last_seen))
+            } else {
+                self.messages
+                    .last()
+                    .is_some_and(|m| !m.is_unread(last_seen))
+            };
+            if found {
+                break;
+            }
+            self.load_messages(phone_number).await?;
+            pages += 1;
+        }
+
+        let first_unread = if self.reversed {
+            self.visible().position(|m| m.is_unread(last_seen))
+        } else {
+            self.visible()
+                .take_while(|m| m.is_unread(last_seen))
+                .count()
+                .checked_sub(1)
+        };
+        let newest = if self.reversed && !self.has_more {
+            self.visible_len().checked_sub(1)
+        } else {
+            None
+        };
+        if let Some(idx) = first_unread.or(newest) {
+            self.select_row(idx);
+        }
+        Ok(())
     }
 
     fn reset(&mut self) {
+        // Any response for a load started before this reset is now stale.
+        self.loads.cancel();
         self.current_offset = 0;
+        self.window_start = 0;
+        self.evicted_newest_id = None;
         self.has_more = true;
         self.is_selected_outgoing = false;
         self.messages.clear();
-        self.state = TableState::default();
+        self.state = TableState::default().with_selected_column(self.selected_column);
     }
 
     async fn reload(&mut self, phone_number: &str) -> AppResult<()> {
@@ -147,69 +765,464 @@ impl MessagesView {
         self.load_messages(phone_number).await
     }
 
+    /// Load the next page. When searching, keep paging through history (up to a limit each
+    /// time) until a page worth of matches is found or there is nothing more to load.
     async fn load_messages(&mut self, phone_number: &str) -> AppResult<()> {
-        if self.is_loading {
+        if self.search.is_none() {
+            return self.fetch_page(phone_number).await;
+        }
+
+        let matched = self.messages.len();
+        for _ in 0..MAX_SEARCH_PAGES {
+            self.fetch_page(phone_number).await?;
+            if !self.search_wants_more(matched) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether a search should keep paging, as it hasn't found a page worth of matches
+    /// since `matched` messages were loaded.
+    fn search_wants_more(&self, matched: usize) -> bool {
+        self.search.is_some()
+            && self.has_more
+            && self.messages.len() - matched < MESSAGES_PER_PAGE as usize
+    }
+
+    /// Fetch the next page, waiting for it. Skipped if a page is already loading.
+    async fn fetch_page(&mut self, phone_number: &str) -> AppResult<()> {
+        let Some(generation) = self.loads.start() else {
+            return Ok(());
+        };
+        let result = Self::request_page(
+            &self.context.http,
+            phone_number,
+            self.current_offset,
+            MESSAGES_PER_PAGE,
+            self.reversed,
+        )
+        .await;
+        if !self.loads.finish(generation) {
             return Ok(());
         }
 
+        let (messages, statuses) = result?;
+        self.apply_next_page(messages, &statuses);
+        Ok(())
+    }
+
+    /// Load a page in the background, handed back through `AppAction::MessagesPage` so the
+    /// view keeps responding meanwhile. Skipped if a page is already loading.
+    fn spawn_page(&mut self, phone_number: &str, kind: PageKind) {
+        let (offset, limit) = match kind {
+            PageKind::Next { .. } => (self.current_offset, MESSAGES_PER_PAGE),
+            PageKind::Evicted { offset } => (offset, self.window_start - offset),
+        };
+        let Some(generation) = self.loads.start() else {
+            return;
+        };
+
+        let http = Arc::clone(&self.context.http);
+        let sender = self.context.sender.clone();
+        let reversed = self.reversed;
+        let phone_number = phone_number.to_string();
+        tokio::spawn(async move {
+            let result = Self::request_page(&http, &phone_number, offset, limit, reversed)
+                .await
+                .map_err(ViewStateRequest::from);
+            let page = MessagesPage {
+                phone_number,
+                generation,
+                kind,
+                result,
+            };
+            let _ = sender.send(AppAction::MessagesPage(page));
+        });
+    }
+
+    /// Apply a page loaded in the background, unless its load was superseded while in
+    /// flight (eg: by a reload). A search keeps paging, up to a limit each time, until a
+    /// page worth of matches is found or there is nothing more to load.
+    pub fn apply_page(&mut self, page: MessagesPage) -> Option<AppAction> {
+        if !self.loads.finish(page.generation) {
+            return None;
+        }
+        let (messages, statuses) = match page.result {
+            Ok(contents) => contents,
+            Err(state) => {
+                return Some(AppAction::SetViewState {
+                    state,
+                    dismiss_modal: false,
+                })
+            }
+        };
+
+        match page.kind {
+            PageKind::Next { matched, pages } => {
+                self.apply_next_page(messages, &statuses);
+                if pages < MAX_SEARCH_PAGES && self.search_wants_more(matched) {
+                    let kind = PageKind::Next {
+                        matched,
+                        pages: pages + 1,
+                    };
+                    self.spawn_page(&page.phone_number, kind);
+                }
+            }
+            PageKind::Evicted { offset } => self.apply_evicted_page(offset, messages, &statuses),
+        }
+        None
+    }
+
+    /// Request a page of messages from a server offset, along with the delivery statuses of
+    /// any outgoing ones.
+    async fn request_page(
+        http: &Arc<HttpClient>,
+        phone_number: &str,
+        offset: u64,
+        limit: u64,
+        reversed: bool,
+    ) -> AppResult<PageContents> {
         let pagination = HttpPaginationOptions::default()
-            .with_limit(MESSAGES_PER_PAGE)
-            .with_offset(self.current_offset)
-            .with_reverse(self.reversed);
+            .with_limit(limit)
+            .with_offset(offset)
+            .with_reverse(reversed);
 
-        self.is_loading = true;
-        let result = self
-            .context
-            .0
-            .as_ref()
+        let messages = http
             .get_messages(phone_number, Some(pagination))
-            .await;
-        self.is_loading = false;
-
-        match result {
-            Ok(messages) => {
-                let count = messages.len();
-                if count > 0 {
-                    self.handle_new_messages(messages);
+            .await
+            .map_err(|e| AppError::Http(Box::new(e)))?;
+        let statuses = Self::fetch_delivery_statuses(http, &messages).await;
+        Ok((messages, statuses))
+    }
+
+    /// Add the next page to those loaded, evicting from the front if over the cap.
+    fn apply_next_page(
+        &mut self,
+        messages: Vec<SmsMessage>,
+        statuses: &HashMap<i64, SmsDeliveryReportStatusCategory>,
+    ) {
+        let count = messages.len();
+        if count > 0 {
+            self.handle_new_messages(messages, statuses);
+        }
+        self.has_more = count == MESSAGES_PER_PAGE as usize;
+        self.evict_front();
+    }
+
+    /// How many loaded messages are over the `max_loaded_messages` cap, if any. Eviction
+    /// follows the server's order, so it's skipped while a sort or search has changed it.
+    fn eviction_excess(&self) -> Option<usize> {
+        let cap = self.context.options.max_loaded_messages?;
+        if self.sort.is_some() || self.search.is_some() {
+            return None;
+        }
+        self.messages
+            .len()
+            .checked_sub(cap)
+            .filter(|excess| *excess > 0)
+    }
+
+    /// Evict the messages furthest above the selection once a page down goes over the cap.
+    /// The selection and viewport move up with the remaining rows so nothing on screen
+    /// changes, and `window_start` moves down so scrolling back to the top re-fetches them.
+    fn evict_front(&mut self) {
+        let Some(excess) = self.eviction_excess() else {
+            return;
+        };
+        let evicted: Vec<SmsMessageTableRecord> = self.messages.drain(..excess).collect();
+        let evicted_visible = evicted.iter().filter(|m| self.filter.matches(m)).count();
+        if !self.reversed {
+            self.evicted_newest_id = evicted
+                .iter()
+                .map(|m| m.message_id)
+                .chain(self.evicted_newest_id)
+                .max();
+        }
+        self.window_start += excess as u64;
+
+        if let Some(selected) = self.state.selected() {
+            self.state
+                .select(Some(selected.saturating_sub(evicted_visible)));
+        }
+        *self.state.offset_mut() = self.state.offset().saturating_sub(evicted_visible);
+
+        self.total_messages = self.messages.len();
+        self.update_constraints();
+        self.update_scroll_state();
+    }
+
+    /// Evict the messages furthest below the selection once a page back up goes over the cap.
+    /// The pagination offset moves back to the first of them, so they load again as pages.
+    fn evict_back(&mut self) {
+        let Some(excess) = self.eviction_excess() else {
+            return;
+        };
+        let keep = self.messages.len() - excess;
+        self.messages.truncate(keep);
+        self.current_offset = self.window_start + keep as u64;
+        self.has_more = true;
+
+        let visible_len = self.visible_len();
+        if let Some(selected) = self.state.selected() {
+            self.state
+                .select(Some(selected.min(visible_len.saturating_sub(1))));
+        }
+
+        self.total_messages = self.messages.len();
+        self.update_constraints();
+        self.update_scroll_state();
+    }
+
+    /// Re-fetch the page of evicted messages just above the first loaded one in the
+    /// background, once the selection nears the top.
+    fn check_load_evicted(&mut self, phone_number: &str) {
+        if self.window_start == 0 || self.loads.is_loading() {
+            return;
+        }
+        if self
+            .state
+            .selected()
+            .is_none_or(|selected| selected >= LOAD_THRESHOLD)
+        {
+            return;
+        }
+
+        let offset = self.window_start - self.window_start.min(MESSAGES_PER_PAGE);
+        self.spawn_page(phone_number, PageKind::Evicted { offset });
+    }
+
+    /// Insert a re-fetched page of evicted messages above those loaded. They're inserted
+    /// above the viewport, which shifts down with the selection so the selected message
+    /// stays put on screen.
+    fn apply_evicted_page(
+        &mut self,
+        offset: u64,
+        messages: Vec<SmsMessage>,
+        statuses: &HashMap<i64, SmsDeliveryReportStatusCategory>,
+    ) {
+        let (timestamps, newlines) = (
+            &self.context.options.timestamps,
+            self.context.options.content_newlines,
+        );
+        let mut records: Vec<SmsMessageTableRecord> = messages
+            .into_iter()
+            .filter(|message| message.message_id.is_some_and(|id| !self.is_loaded(id)))
+            .map(|message| {
+                let status = message.message_id.and_then(|id| statuses.get(&id)).cloned();
+                SmsMessageTableRecord::new(message, timestamps, newlines, status)
+            })
+            .collect();
+
+        let inserted_visible = records.iter().filter(|m| self.filter.matches(m)).count();
+        self.window_start = offset;
+        if offset == 0 {
+            self.evicted_newest_id = None;
+        }
+        records.append(&mut self.messages);
+        self.messages = records;
+
+        if let Some(selected) = self.state.selected() {
+            self.select_row(selected + inserted_visible);
+        }
+        *self.state.offset_mut() += inserted_visible;
+
+        self.total_messages = self.messages.len();
+        self.update_constraints();
+        self.update_scroll_state();
+        self.evict_back();
+    }
+
+    /// Fetch the latest delivery report status for each outgoing message, a few requests at
+    /// a time. Messages without any reports (or whose request failed) are left out.
+    async fn fetch_delivery_statuses(
+        http: &Arc<HttpClient>,
+        messages: &[SmsMessage],
+    ) -> HashMap<i64, SmsDeliveryReportStatusCategory> {
+        let mut statuses = HashMap::new();
+        let mut collect = |result| {
+            if let Ok(Some((message_id, status))) = result {
+                statuses.insert(message_id, status);
+            }
+        };
+
+        let mut tasks = JoinSet::new();
+        for message_id in messages
+            .iter()
+            .filter(|m| m.is_outgoing)
+            .filter_map(|m| m.message_id)
+        {
+            if tasks.len() >= MAX_STATUS_REQUESTS {
+                if let Some(result) = tasks.join_next().await {
+                    collect(result);
                 }
-                self.has_more = count == MESSAGES_PER_PAGE as usize;
-                Ok(())
             }
-            Err(e) => Err(AppError::Http(Box::new(e))),
+
+            let http = Arc::clone(http);
+            tasks.spawn(async move {
+                // The server doesn't guarantee an order, so pick the newest by time
+                // rather than trusting the first.
+                let pagination = HttpPaginationOptions::default().with_limit(STATUS_REPORTS_LIMIT);
+                let reports = http
+                    .get_delivery_reports(message_id, Some(pagination))
+                    .await
+                    .ok()?;
+                let latest = reports.iter().max_by_key(|report| report.created_at)?;
+                Some((
+                    message_id,
+                    SmsDeliveryReportStatusCategory::from(latest.status),
+                ))
+            });
         }
+
+        while let Some(result) = tasks.join_next().await {
+            collect(result);
+        }
+        statuses
     }
 
     /// Takes ownership of messages Vec to avoid intermediate allocations
-    fn handle_new_messages(&mut self, new_messages: Vec<SmsMessage>) {
+    fn handle_new_messages(
+        &mut self,
+        new_messages: Vec<SmsMessage>,
+        statuses: &HashMap<i64, SmsDeliveryReportStatusCategory>,
+    ) {
+        let (timestamps, newlines) = (
+            &self.context.options.timestamps,
+            self.context.options.content_newlines,
+        );
+        // Messages merged in since the last page shift the server's offsets, so a page
+        // can repeat some already loaded.
+        let mut records: Vec<SmsMessageTableRecord> = new_messages
+            .into_iter()
+            .filter(|message| message.message_id.is_some_and(|id| !self.is_loaded(id)))
+            .map(|message| {
+                let status = message.message_id.and_then(|id| statuses.get(&id)).cloned();
+                SmsMessageTableRecord::new(message, timestamps, newlines, status)
+            })
+            .collect();
+        self.retain_search_matches(&mut records);
+
         if self.current_offset == 0 {
-            // First load: convert and replace
-            self.messages = new_messages
-                .into_iter()
-                .map(SmsMessageTableRecord::from)
-                .collect();
-            self.state.select(Some(0));
+            // First load: replace
+            self.messages = records;
+            self.sort_loaded();
         } else {
-            // Append: extend with converted messages
-            self.messages
-                .extend(new_messages.into_iter().map(SmsMessageTableRecord::from));
+            // Append: extend with converted messages, sorting them into place
+            let selected_id = self.selected_message().map(|m| m.message_id);
+            self.messages.extend(records);
+            if self.sort.is_some() {
+                self.sort_loaded();
+                self.reselect(selected_id);
+            }
+        }
+
+        // Searching may only find the first match a few pages in.
+        if self.state.selected().is_none() {
+            self.state.select((self.visible_len() > 0).then_some(0));
         }
 
         self.current_offset += MESSAGES_PER_PAGE;
         self.total_messages = self.messages.len();
         self.update_constraints();
-        self.scroll_state =
-            ScrollbarState::new(self.messages.len().saturating_sub(1) * ITEM_HEIGHT);
+        self.update_scroll_state();
+    }
+
+    /// Loaded messages matching the active direction filter, which the
+    /// table selection indexes into.
+    fn visible(&self) -> impl Iterator<Item = &SmsMessageTableRecord> {
+        self.messages.iter().filter(|m| self.filter.matches(m))
+    }
+
+    fn visible_len(&self) -> usize {
+        self.visible().count()
+    }
+
+    fn selected_message(&self) -> Option<&SmsMessageTableRecord> {
+        self.visible().nth(self.state.selected()?)
+    }
+
+    fn update_scroll_state(&mut self) {
+        let (length, position) = Self::scroll_rows(
+            self.visible_len(),
+            self.state.selected().unwrap_or(0),
+            self.item_height,
+        );
+        self.scroll_state = ScrollbarState::new(length).position(position);
+    }
+
+    /// The content column's text, wrapped once it's wider than 80 columns on screen.
+    fn content_cell_text(content: &str) -> String {
+        if content.width() > 80 {
+            format!("\n{}\n", textwrap::fill(content, 80))
+        } else {
+            format!("\n{content}\n")
+        }
+    }
+
+    /// Rows per message, taller when content keeps its newlines so more than a line shows.
+    fn item_height(newlines: ContentNewlines) -> usize {
+        match newlines {
+            ContentNewlines::Keep => MULTILINE_ITEM_HEIGHT,
+            ContentNewlines::Strip | ContentNewlines::Symbol => DEFAULT_ITEM_HEIGHT,
+        }
+    }
+
+    /// The scrollbar content length and position in terminal rows, for `len` items
+    /// of `item_height` rows with `selected` highlighted.
+    fn scroll_rows(len: usize, selected: usize, item_height: usize) -> (usize, usize) {
+        (len.saturating_sub(1) * item_height, selected * item_height)
+    }
+
+    /// The item drawn `body_row` rows below the header, given `body_height` rows for
+    /// the table body. The table only draws items that fit entirely, so the rows left
+    /// under the last whole item are empty.
+    fn item_at_row(
+        offset: usize,
+        body_row: usize,
+        body_height: usize,
+        item_height: usize,
+    ) -> Option<usize> {
+        let item_height = item_height.max(1);
+        let slot = body_row / item_height;
+        (slot < body_height / item_height).then_some(offset + slot)
+    }
+
+    /// Switch to the next filter, keeping the selected message if it is still visible.
+    fn cycle_filter(&mut self) {
+        let selected_id = self.selected_message().map(|m| m.message_id);
+        self.filter = self.filter.next();
+
+        let visible_len = self.visible_len();
+        let idx = selected_id
+            .and_then(|id| self.visible().position(|m| m.message_id == id))
+            .or((visible_len > 0).then_some(0));
+
+        self.state.select(idx);
+        self.update_scroll_state();
+        self.update_selection(idx.unwrap_or(0));
     }
 
     fn update_constraints(&mut self) {
         let id_len = self
             .messages
             .iter()
-            .map(|m| m.identifier.width())
+            .map(|m| {
+                let label = self.context.state.message_label(m.message_id);
+                m.identifier.width() + label.map_or(0, |label| label.glyph().width() + 1)
+            })
             .max()
             .unwrap_or(10)
             .min(20);
 
+        // Depends on the configured date and time formats
+        let timestamp_len = self
+            .messages
+            .iter()
+            .map(|m| m.timestamp.width())
+            .max()
+            .unwrap_or(16);
+
         let content_len = self
             .messages
             .iter()
@@ -226,66 +1239,291 @@ impl MessagesView {
 
         self.longest_item_lens = (
             u16::try_from(id_len).unwrap_or(0),
-            8,  // direction_len is constant
-            16, // timestamp_len is constant
+            9, // direction_len is constant, including the delivery status glyph
+            u16::try_from(timestamp_len).unwrap_or(16),
             u16::try_from(content_len).unwrap_or(0),
         );
     }
 
-    async fn check_load_more(&mut self, phone_number: &str) -> AppResult<()> {
-        if !self.has_more || self.is_loading || self.messages.is_empty() {
-            return Ok(());
+    /// Load the next page in the background once the selection nears the end.
+    fn check_load_more(&mut self, phone_number: &str) {
+        if !self.has_more || self.loads.is_loading() || self.messages.is_empty() {
+            return;
         }
 
+        // Pages are fetched against the full list, but the threshold is
+        // relative to what is visible so filtered views keep paginating.
         if let Some(selected) = self.state.selected() {
-            let load_point = self.messages.len().saturating_sub(LOAD_THRESHOLD);
+            let load_point = self.visible_len().saturating_sub(LOAD_THRESHOLD);
             if selected >= load_point {
-                self.load_messages(phone_number).await?;
+                let kind = PageKind::Next {
+                    matched: self.messages.len(),
+                    pages: 1,
+                };
+                self.spawn_page(phone_number, kind);
             }
         }
-        Ok(())
     }
 
-    fn next_row(&mut self) {
-        if self.messages.is_empty() {
-            return;
+    /// Move the selection down a row. With `wrap`, the last row moves back to the
+    /// first, returning true so the caller can skip loading more messages.
+    fn next_row(&mut self, wrap: bool) -> bool {
+        let visible_len = self.visible_len();
+        if visible_len == 0 {
+            return false;
         }
 
         let current = self.state.selected().unwrap_or(0);
-        let next = (current + 1).min(self.messages.len() - 1);
+        let wrapped = wrap && current + 1 >= visible_len;
+        let next = if wrapped {
+            0
+        } else {
+            (current + 1).min(visible_len - 1)
+        };
 
         if next != current {
             self.state.select(Some(next));
-            self.scroll_state = self.scroll_state.position(next * ITEM_HEIGHT);
+            self.update_scroll_state();
             self.update_selection(next);
         }
+        wrapped
     }
 
-    fn previous_row(&mut self) {
-        if self.messages.is_empty() {
-            return;
+    /// Move the selection up a row. With `wrap`, the first row moves to the last,
+    /// returning true so the caller can skip re-fetching evicted messages.
+    fn previous_row(&mut self, wrap: bool) -> bool {
+        let visible_len = self.visible_len();
+        if visible_len == 0 {
+            return false;
         }
 
         let current = self.state.selected().unwrap_or(0);
-        let previous = current.saturating_sub(1);
+        let wrapped = wrap && current == 0;
+        let previous = if wrapped {
+            visible_len - 1
+        } else {
+            current.saturating_sub(1)
+        };
 
         if previous != current {
             self.state.select(Some(previous));
-            self.scroll_state = self.scroll_state.position(previous * ITEM_HEIGHT);
+            self.update_scroll_state();
             self.update_selection(previous);
         }
+        wrapped
+    }
+
+    fn select_row(&mut self, idx: usize) {
+        self.state.select(Some(idx));
+        self.update_scroll_state();
+        self.update_selection(idx);
+    }
+
+    /// Find the visible row index at a terminal position, accounting for the
+    /// header and the table's scroll offset.
+    fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.table_area;
+        if !area.contains(Position::new(column, row)) || row == area.y {
+            return None;
+        }
+
+        let idx = Self::item_at_row(
+            self.state.offset(),
+            usize::from(row - area.y - 1),
+            usize::from(area.height - 1),
+            self.item_height,
+        )?;
+        (idx < self.visible_len()).then_some(idx)
+    }
+
+    pub async fn handle_mouse(
+        &mut self,
+        mouse: MouseEvent,
+        phone_number: &str,
+    ) -> Option<AppAction> {
+        let view_state = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let idx = self.row_at(mouse.column, mouse.row)?;
+                self.select_row(idx);
+                None
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp
+                if !self
+                    .table_area
+                    .contains(Position::new(mouse.column, mouse.row)) =>
+            {
+                None
+            }
+            MouseEventKind::ScrollDown => {
+                for _ in 0..SCROLL_ROWS {
+                    self.next_row(false);
+                }
+                self.check_load_more(phone_number);
+                None
+            }
+            MouseEventKind::ScrollUp => {
+                for _ in 0..SCROLL_ROWS {
+                    self.previous_row(false);
+                }
+                self.check_load_evicted(phone_number);
+                None
+            }
+            _ => None,
+        };
+
+        view_state.map(|state| AppAction::SetViewState {
+            state,
+            dismiss_modal: false,
+        })
+    }
+
+    /// Open a link, reporting failures as a notification rather than an error.
+    fn open_link(&self, url: &str) {
+        if let Err(e) = links::open_url(url) {
+            let notification = NotificationType::Failure {
+                title: "Failed to Open Link".to_string(),
+                message: e.to_string(),
+            };
+            let _ = self
+                .context
+                .sender
+                .send(AppAction::ShowNotification(notification));
+        }
     }
 
     fn update_selection(&mut self, idx: usize) {
-        self.is_selected_outgoing = self.messages.get(idx).is_some_and(|m| m.is_outgoing);
+        let outgoing = self.visible().nth(idx).is_some_and(|m| m.is_outgoing);
+        self.is_selected_outgoing = outgoing;
+    }
+
+    /// Sort the loaded messages by the active sort, if any. This doesn't keep the selection.
+    fn sort_loaded(&mut self) {
+        if let Some((column, descending)) = self.sort {
+            self.messages.sort_by(|a, b| {
+                let ordering = a.compare_by_column(b, column);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+    }
+
+    /// Select a message by ID again after the loaded messages were reordered.
+    fn reselect(&mut self, message_id: Option<i64>) {
+        if let Some(idx) = message_id.and_then(|id| self.visible().position(|m| m.message_id == id))
+        {
+            self.select_row(idx);
+        }
+    }
+
+    /// Sort by the selected column, or reverse the sort if it's already sorted by it.
+    fn toggle_sort(&mut self) -> Option<NotificationType> {
+        let Some(column) = self.selected_column else {
+            return Some(NotificationType::Failure {
+                title: "Not Sorted".to_string(),
+                message: format!("Select a column with ({}) first.", glyphs().keys_left_right),
+            });
+        };
+
+        let descending = matches!(self.sort, Some((sorted, false)) if sorted == column);
+        self.sort = Some((column, descending));
+
+        let selected_id = self.selected_message().map(|m| m.message_id);
+        self.sort_loaded();
+        self.reselect(selected_id);
+        None
+    }
+
+    /// Copy the selected column of every visible message to the clipboard, one per line.
+    fn copy_selected_column(&self) -> NotificationType {
+        const COLUMN_NAMES: [&str; 4] = ["IDs", "directions", "times", "contents"];
+
+        let Some(column) = self.selected_column else {
+            return NotificationType::Failure {
+                title: "Nothing Copied".to_string(),
+                message: format!("Select a column with ({}) first.", glyphs().keys_left_right),
+            };
+        };
+
+        let values: Vec<&str> = self.visible().map(|m| m.ref_array()[column]).collect();
+        match clipboard::copy(&values.join("\n")) {
+            Ok(()) => NotificationType::GenericMessage {
+                color: Color::Green,
+                icon: glyphs().copied.to_string(),
+                title: "Copied".to_string(),
+                message: format!("Copied {} message {}.", values.len(), COLUMN_NAMES[column]),
+            },
+            Err(e) => NotificationType::Failure {
+                title: "Copy Failed".to_string(),
+                message: e.to_string(),
+            },
+        }
+    }
+
+    /// Copy every loaded message as a plain text transcript, oldest first, using the
+    /// unsanitized content. Notes when older messages haven't been loaded yet.
+    fn copy_transcript(&self, phone_number: &str) -> NotificationType {
+        let mut messages: Vec<&SmsMessageTableRecord> = self.messages.iter().collect();
+        if messages.is_empty() {
+            return NotificationType::Failure {
+                title: "Nothing Copied".to_string(),
+                message: "There are no loaded messages to copy.".to_string(),
+            };
+        }
+        messages.sort_by_key(|m| (m.unix_time(), m.message_id));
+
+        let mut lines: Vec<String> = messages
+            .iter()
+            .map(|m| {
+                let direction = if m.is_outgoing { "OUT" } else { "IN" };
+                format!(
+                    "[{}] {direction}: {}",
+                    m.timestamp,
+                    m.original_message
+                        .as_ref()
+                        .map_or(&m.content, |message| &message.message_content)
+                )
+            })
+            .collect();
+        if self.has_more {
+            lines.push(format!(
+                "(Partial conversation with {phone_number}, older messages weren't loaded)"
+            ));
+        }
+
+        match clipboard::copy(&lines.join("\n")) {
+            Ok(()) => NotificationType::GenericMessage {
+                color: Color::Green,
+                icon: glyphs().copied.to_string(),
+                title: "Copied".to_string(),
+                message: format!("Copied {} messages.", messages.len()),
+            },
+            Err(e) => NotificationType::Failure {
+                title: "Copy Failed".to_string(),
+                message: e.to_string(),
+            },
+        }
     }
 
     fn next_column(&mut self) {
-        self.state.select_next_column();
+        let next = self
+            .selected_column
+            .map_or(0, |column| (column + 1).min(COLUMNS.len() - 1));
+        self.select_column(next);
     }
 
     fn previous_column(&mut self) {
-        self.state.select_previous_column();
+        let previous = self
+            .selected_column
+            .map_or(COLUMNS.len() - 1, |column| column.saturating_sub(1));
+        self.select_column(previous);
+    }
+
+    fn select_column(&mut self, column: usize) {
+        self.selected_column = Some(column);
+        self.state.select_column(Some(column));
     }
 
     fn render_table(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
@@ -298,15 +1536,26 @@ impl MessagesView {
             .add_modifier(Modifier::REVERSED)
             .fg(theme.cell_selected_fg);
 
-        let header = ["ID", "Dir", "Time", "Content"]
+        let header = COLUMNS
             .into_iter()
             .map(Cell::from)
             .collect::<Row>()
             .style(header_style)
             .height(1);
 
-        let rows = self.messages.iter().enumerate().map(|(i, msg)| {
-            let color = if i % 2 == 0 {
+        let direction_colors = self.context.options.direction_colors;
+        let unread_marker = self.unread_marker();
+        let marker_style = Style::default()
+            .fg(theme.text_accent)
+            .add_modifier(Modifier::DIM);
+        let rows = self.visible().enumerate().map(|(i, msg)| {
+            let color = if direction_colors {
+                if msg.is_outgoing {
+                    theme.row_outgoing_bg
+                } else {
+                    theme.row_incoming_bg
+                }
+            } else if i % 2 == 0 {
                 theme.row_normal_bg
             } else {
                 theme.row_alt_bg
@@ -316,20 +1565,59 @@ impl MessagesView {
                 .into_iter()
                 .enumerate()
                 .map(|(idx, content)| {
-                    // Only wrap content column (idx 3) if needed
-                    let text = if idx == 3 && content.len() > 80 {
-                        format!("\n{}\n", textwrap::fill(content, 80))
+                    // A labelled message's ID (idx 0) is followed by the label's glyph
+                    if idx == 0 {
+                        if let Some(label) = self.context.state.message_label(msg.message_id) {
+                            return Cell::from(Text::from(format!(
+                                "\n{content} {}\n",
+                                label.glyph()
+                            )));
+                        }
+                    }
+
+                    // Outgoing direction (idx 1) is followed by a delivery status glyph
+                    if idx == 1 && msg.is_outgoing {
+                        // A send still in progress is pending, or undelivered once it fails.
+                        let (glyph, style) = match msg.pending {
+                            Some(PendingSend { failed: true, .. }) => status_indicator(
+                                Some(&SmsDeliveryReportStatusCategory::Failed),
+                                theme,
+                            ),
+                            Some(_) => status_indicator(None, theme),
+                            None => status_indicator(msg.delivery_status.as_ref(), theme),
+                        };
+                        return Cell::from(Text::from(vec![
+                            Line::from(""),
+                            Line::from(vec![
+                                Span::raw(format!("{content} ")),
+                                Span::styled(glyph, style),
+                            ]),
+                            Line::from(""),
+                        ]));
+                    }
+
+                    let text = if idx == 3 {
+                        Self::content_cell_text(content)
                     } else {
                         format!("\n{content}\n")
                     };
-                    Cell::from(Text::from(text))
+                    let mut text = Text::from(text);
+
+                    // The new messages marker replaces the content's blank top line
+                    if let Some((_, marker)) = unread_marker
+                        .as_ref()
+                        .filter(|(id, _)| idx == 3 && *id == msg.message_id)
+                    {
+                        text.lines[0] = Line::styled(marker.clone(), marker_style);
+                    }
+                    Cell::from(text)
                 })
                 .collect::<Row>()
                 .style(Style::new().fg(theme.text_primary).bg(color))
-                .height(4)
+                .height(u16::try_from(self.item_height).unwrap_or(u16::MAX))
         });
 
-        let bar = " █ ";
+        let bar = format!(" {} ", glyphs().selection);
         let t = Table::new(
             rows,
             [
@@ -345,7 +1633,7 @@ impl MessagesView {
         .cell_highlight_style(selected_cell_style)
         .highlight_symbol(Text::from(vec![
             Line::from(""),
-            Line::from(bar),
+            Line::from(bar.clone()),
             Line::from(bar),
             Line::from(""),
         ]))
@@ -370,41 +1658,84 @@ impl MessagesView {
     }
 
     fn render_footer(&self, frame: &mut Frame, area: Rect, phone_number: &str, theme: &Theme) {
-        let base_controls = "(↑/↓) navigate | (←/→) columns | (Ctrl+R) order";
-        let action_controls = if self.is_selected_outgoing {
-            "(Esc) back | (r) reload | (c) compose SMS | (m) delivery reports"
-        } else {
-            "(Esc) back | (r) reload | (c) compose SMS"
-        };
-
         let order_indicator = if self.reversed {
-            "↓ Oldest First"
+            format!("{} Oldest First", glyphs().arrow_down)
         } else {
-            "↑ Newest First"
+            format!("{} Newest First", glyphs().arrow_up)
         };
 
+        let name = self.display_name(phone_number);
         let status_line = if !self.messages.is_empty() {
-            let status = if self.is_loading {
-                "⟳ Loading more..."
+            let status = if self.loads.is_loading() {
+                format!("{}Loading more...", glyphs().loading)
+            } else if self.has_more {
+                format!("More available {}", glyphs().arrow_down)
+            } else {
+                format!("All loaded{}", glyphs().all_loaded)
+            };
+            // The server doesn't report a conversation total, only whether more pages exist.
+            let noun = if self.search.is_some() {
+                "matches"
+            } else {
+                "messages"
+            };
+            let count = if self.window_start > 0 {
+                format!("{} {noun} loaded (+earlier)", self.total_messages)
             } else if self.has_more {
-                "More available ↓"
+                format!("{} {noun} loaded (+more)", self.total_messages)
+            } else {
+                format!("{} {noun}", self.total_messages)
+            };
+            let count = if self.filter == DirectionFilter::All {
+                count
             } else {
-                "All loaded ✓"
+                format!("{count} | Showing {}", self.filter.label())
             };
+            let glyphs = glyphs();
             format!(
-                "💬 {} | ✉️ {} messages | {} | {}",
-                phone_number, self.total_messages, order_indicator, status
+                "{}{} | {}{} | {} | {} | {}{}",
+                glyphs.conversation,
+                name,
+                glyphs.message_count,
+                count,
+                order_indicator,
+                status,
+                glyphs.timezone,
+                self.context.options.timestamps.timezone
             )
-        } else if self.is_loading {
-            "⟳ Loading messages...".to_string()
+        } else if self.loads.is_loading() {
+            format!("{}Loading messages...", glyphs().loading)
         } else if !phone_number.is_empty() {
-            format!("💬 {phone_number} | No messages found | {order_indicator}")
+            format!(
+                "{}{name} | No messages found | {order_indicator}",
+                glyphs().conversation
+            )
         } else {
             String::new()
         };
+        let status_line = match self.sort {
+            Some((column, descending)) if !self.messages.is_empty() => {
+                let arrow = if descending {
+                    glyphs().arrow_down
+                } else {
+                    glyphs().arrow_up
+                };
+                format!("{status_line} | Sorted: {} {arrow}", COLUMNS[column])
+            }
+            _ => status_line,
+        };
+        let status_line = match self.selected_column {
+            Some(column) if !self.messages.is_empty() => {
+                format!("{status_line} | Column: {}", COLUMNS[column])
+            }
+            _ => status_line,
+        };
+        let status_line = match &self.search {
+            Some(query) => format!("{}\"{query}\" | {status_line}", glyphs().search),
+            None => status_line,
+        };
 
-        let footer_text = format!("{base_controls}\n{action_controls}\n{status_line}");
-        let info_footer = Paragraph::new(footer_text)
+        let info_footer = Paragraph::new(status_line)
             .style(theme.primary_style)
             .centered()
             .block(
@@ -421,54 +1752,181 @@ impl ViewBase for MessagesView {
 
     async fn load(&mut self, ctx: Self::Context<'_>) -> AppResult<()> {
         self.reversed = ctx.1;
+        // Taken before this visit marks the conversation seen, for the new messages marker.
+        self.unread_since = self.context.state.last_seen(ctx.0);
         self.reload(ctx.0).await?;
-        self.is_selected_outgoing = self.messages.first().is_some_and(|m| m.is_outgoing);
+        let restored = match self.restore.take() {
+            Some(position) => self.restore_position(ctx.0, position).await?,
+            None => false,
+        };
+        if !restored {
+            self.select_first_unread(ctx.0).await?;
+        }
+        self.mark_seen();
+        self.is_selected_outgoing = self.selected_message().is_some_and(|m| m.is_outgoing);
         Ok(())
     }
 
     async fn handle_key(&mut self, key: KeyEvent, ctx: Self::Context<'_>) -> Option<AppAction> {
+        // Navigation wins over the lowercase shortcuts, so labelling moves to L.
+        let key = if self.context.options.vim_keys {
+            vim_navigation_key(key)
+        } else {
+            key
+        };
+
         let view_state = match key.code {
-            KeyCode::Esc => {
+            KeyCode::Esc if self.search.is_some() => {
+                // Leave the search, back to the whole conversation.
                 self.reset();
+                Some(ViewStateRequest::view_messages(ctx.0))
+            }
+            KeyCode::Esc => {
+                // Not reset, so the position can be remembered when leaving.
+                self.unread_since = None;
                 Some(ViewStateRequest::Phonebook)
             }
-            KeyCode::Char('c' | 'C') => Some(ViewStateRequest::Compose {
-                phone_number: ctx.0.to_string(),
-            }),
-            KeyCode::Char('r' | 'R') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.reset();
-                Some(ViewStateRequest::Messages {
+            KeyCode::Char('/') => {
+                let mut ui = TextInputModal::new("Search Messages", "Find messages containing")
+                    .with_max_length(100);
+                if let Some(query) = &self.search {
+                    ui = ui.with_initial_value(query);
+                }
+                let modal = AppModal::new("search_messages", ui)
+                    .with_metadata(ModalMetadata::PhoneNumber(ctx.0.to_string()));
+                return Some(AppAction::SetModal(Some(modal)));
+            }
+            KeyCode::Char('C' | 'q' | 'Q') => {
+                // Quote the selected message into a new compose buffer.
+                let message = self.selected_message()?;
+                Some(ViewStateRequest::Compose {
                     phone_number: ctx.0.to_string(),
-                    reversed: !self.reversed,
+                    initial_text: Some(format!("> {}\n", message.content)),
                 })
             }
+            KeyCode::Char('c') => Some(ViewStateRequest::compose(ctx.0)),
+            KeyCode::Char('r' | 'R') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Remember the order for next time this conversation is opened.
+                self.context.state.set_reversed(ctx.0, !self.reversed);
+
+                self.reset();
+                match self.search.clone() {
+                    // Searches always use the remembered order, which was just changed.
+                    Some(query) => Some(ViewStateRequest::SearchMessages {
+                        phone_number: ctx.0.to_string(),
+                        query,
+                    }),
+                    None => Some(ViewStateRequest::Messages {
+                        phone_number: ctx.0.to_string(),
+                        reversed: Some(!self.reversed),
+                    }),
+                }
+            }
             KeyCode::Char('r' | 'R') => match self.reload(ctx.0).await {
                 Ok(()) => None,
                 Err(e) => Some(ViewStateRequest::from(e)),
             },
             KeyCode::Char('m' | 'M') => {
-                let selected = self.state.selected()?;
-                let message = self.messages.get(selected)?;
+                let message = self.selected_message()?;
                 if !message.is_outgoing {
                     return None;
                 }
 
                 // Clone only when actually needed for the modal
-                return message.original_message.as_ref().map(|orig| {
-                    let modal =
-                        AppModal::new("delivery_reports", DeliveryReportsModal::new(orig.clone()));
-                    AppAction::SetModal(Some(modal))
-                });
+                let modal = AppModal::new(
+                    "delivery_reports",
+                    DeliveryReportsModal::new(
+                        message.original_message.clone()?,
+                        self.context.options.timestamps.clone(),
+                    ),
+                );
+                return Some(AppAction::SetModal(Some(modal)));
+            }
+            KeyCode::Char('j' | 'J')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.context.options.debug =>
+            {
+                // Pending rows have nothing to inspect until the send resolves.
+                let message = self.selected_message().filter(|m| m.pending.is_none())?;
+
+                let modal = AppModal::new(
+                    "json_inspect",
+                    JsonInspectModal::new(
+                        format!("Message #{}", message.message_id),
+                        message.original_message.as_ref()?,
+                    ),
+                );
+                return Some(AppAction::SetModal(Some(modal)));
+            }
+            KeyCode::Char('o' | 'O') if self.context.options.open_links => {
+                let message = self.selected_message()?;
+                let urls = links::find_urls(&message.content);
+                match urls.len() {
+                    0 => {
+                        let notification = NotificationType::Failure {
+                            title: "No Links".to_string(),
+                            message: "The selected message doesn't contain a link.".to_string(),
+                        };
+                        return Some(AppAction::ShowNotification(notification));
+                    }
+                    1 => {
+                        self.open_link(&urls[0]);
+                        None
+                    }
+                    _ => {
+                        let modal =
+                            AppModal::new("open_link", ListSelectModal::new("Open Link", urls));
+                        return Some(AppAction::SetModal(Some(modal)));
+                    }
+                }
+            }
+            KeyCode::Char('l' | 'L') => {
+                // Pending rows only have a temporary ID, which a saved label would outlive.
+                let message = self.selected_message().filter(|m| m.pending.is_none())?;
+                let mut items: Vec<String> = MessageLabel::ALL
+                    .into_iter()
+                    .map(|label| label.name().to_string())
+                    .collect();
+                if self
+                    .context
+                    .state
+                    .message_label(message.message_id)
+                    .is_some()
+                {
+                    items.push(REMOVE_LABEL.to_string());
+                }
+                let modal = AppModal::new(
+                    "message_label",
+                    ListSelectModal::new("Label Message", items),
+                )
+                .with_metadata(ModalMetadata::MessageId(message.message_id));
+                return Some(AppAction::SetModal(Some(modal)));
+            }
+            KeyCode::Char('f' | 'F') => {
+                self.cycle_filter();
+                None
+            }
+            KeyCode::Char('s') => {
+                let notification = self.toggle_sort()?;
+                return Some(AppAction::ShowNotification(notification));
+            }
+            KeyCode::Char('y' | 'Y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let notification = self.copy_transcript(ctx.0);
+                return Some(AppAction::ShowNotification(notification));
+            }
+            KeyCode::Char('Y') => {
+                let notification = self.copy_selected_column();
+                return Some(AppAction::ShowNotification(notification));
             }
             KeyCode::Down => {
-                self.next_row();
-                match self.check_load_more(ctx.0).await {
-                    Ok(()) => None,
-                    Err(e) => Some(ViewStateRequest::from(e)),
+                if !self.next_row(self.context.options.wrap_navigation) {
+                    self.check_load_more(ctx.0);
                 }
+                None
             }
             KeyCode::Up => {
-                self.previous_row();
+                if !self.previous_row(self.context.options.wrap_navigation) {
+                    self.check_load_evicted(ctx.0);
+                }
                 None
             }
             KeyCode::Right => {
@@ -488,12 +1946,220 @@ impl ViewBase for MessagesView {
         })
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, ctx: Self::Context<'_>) {
-        let layout = Layout::vertical([Constraint::Min(5), Constraint::Length(5)]);
-        let rects = layout.split(frame.area());
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, ctx: Self::Context<'_>) {
+        let layout = Layout::vertical([Constraint::Min(5), Constraint::Length(3)]);
+        let rects = layout.split(area);
 
+        self.table_area = rects[0];
         self.render_table(frame, rects[0], theme);
         self.render_scrollbar(frame, rects[0]);
         self.render_footer(frame, rects[1], ctx.0, theme);
     }
+
+    fn key_hints(&self, _ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        let options = &self.context.options;
+        let (vim_up_down, vim_left_right, label) = if options.vim_keys {
+            (" j/k", " h/l", "L")
+        } else {
+            ("", "", "l")
+        };
+
+        let mut hints = vec![
+            (
+                format!("{}{vim_up_down}", glyphs().keys_up_down),
+                "navigate",
+            ),
+            ("Esc".into(), "back"),
+            ("c".into(), "compose SMS"),
+            ("/".into(), "search"),
+            ("r".into(), "reload"),
+        ];
+        if self.is_selected_outgoing {
+            hints.push(("m".into(), "delivery reports"));
+        }
+        hints.extend([("q".into(), "quote"), (label.into(), "label")]);
+        if options.open_links {
+            hints.push(("o".into(), "open link"));
+        }
+        hints.extend([
+            (
+                format!("{}{vim_left_right}", glyphs().keys_left_right),
+                "columns",
+            ),
+            ("s".into(), "sort"),
+            ("f".into(), "filter"),
+            ("Y".into(), "copy column"),
+            ("Ctrl+Y".into(), "copy all"),
+            ("Ctrl+R".into(), "order"),
+        ]);
+        if options.debug {
+            hints.push(("Ctrl+J".into(), "inspect"));
+        }
+        hints
+    }
+}
+impl ModalResponderComponent for MessagesView {
+    fn handle_modal_response(
+        &mut self,
+        modal: &mut AppModal,
+        response: ModalResponse,
+    ) -> Option<AppAction> {
+        match response {
+            ModalResponse::TextInput(Some(url)) if modal.id == "open_link" => {
+                self.open_link(&url);
+                Some(AppAction::SetModal(None))
+            }
+            ModalResponse::TextInput(Some(item)) if modal.id == "message_label" => {
+                let ModalMetadata::MessageId(message_id) = modal.metadata else {
+                    return None;
+                };
+                // Labels are kept by message ID, so they survive reloads.
+                let label = MessageLabel::from_name(&item);
+                self.context.state.set_message_label(message_id, label);
+                self.update_constraints();
+                Some(AppAction::SetModal(None))
+            }
+            ModalResponse::TextInput(Some(query)) if modal.id == "search_messages" => {
+                let ModalMetadata::PhoneNumber(phone_number) = &modal.metadata else {
+                    return None;
+                };
+                let state = if query.trim().is_empty() {
+                    ViewStateRequest::view_messages(phone_number)
+                } else {
+                    ViewStateRequest::SearchMessages {
+                        phone_number: phone_number.clone(),
+                        query,
+                    }
+                };
+                Some(AppAction::SetViewState {
+                    state,
+                    dismiss_modal: true,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_tracker_allows_one_load_at_a_time() {
+        let mut loads = LoadTracker::default();
+        let generation = loads.start().unwrap();
+        assert!(loads.is_loading());
+        assert_eq!(loads.start(), None);
+
+        assert!(loads.finish(generation));
+        assert!(!loads.is_loading());
+        assert!(loads.start().is_some());
+    }
+
+    #[test]
+    fn load_tracker_drops_superseded_response() {
+        // A page requested before a reload arrives once the reload's own page has started.
+        let mut loads = LoadTracker::default();
+        let stale = loads.start().unwrap();
+        loads.cancel();
+        let fresh = loads.start().unwrap();
+
+        assert!(!loads.finish(stale));
+        assert!(
+            loads.is_loading(),
+            "a stale response doesn't end the current load"
+        );
+        assert!(loads.finish(fresh));
+    }
+
+    #[test]
+    fn load_tracker_drops_response_after_cancel() {
+        let mut loads = LoadTracker::default();
+        let generation = loads.start().unwrap();
+        loads.cancel();
+
+        assert!(!loads.finish(generation));
+        assert!(!loads.is_loading());
+    }
+
+    #[test]
+    fn load_tracker_generations_are_unique_across_views() {
+        let (mut first, mut second) = (LoadTracker::default(), LoadTracker::default());
+        let generation = first.start().unwrap();
+        second.start().unwrap();
+
+        assert!(!second.finish(generation));
+        assert!(first.finish(generation));
+    }
+
+    #[test]
+    fn content_cell_wraps_by_display_width() {
+        // 40 wide characters fill exactly 80 columns, one more needs a wrap.
+        assert_eq!(
+            MessagesView::content_cell_text(&"你".repeat(40))
+                .lines()
+                .count(),
+            2
+        );
+        let wrapped = MessagesView::content_cell_text(&"你".repeat(41));
+        assert!(wrapped.lines().count() > 2);
+        assert!(wrapped.lines().all(|line| line.width() <= 80));
+
+        // Emoji are two columns wide, but only count as one character.
+        assert!(
+            MessagesView::content_cell_text(&"👋 ".repeat(30))
+                .lines()
+                .count()
+                > 2
+        );
+        assert_eq!(
+            MessagesView::content_cell_text(&"a ".repeat(30))
+                .lines()
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn scroll_rows_scale_with_item_height() {
+        assert_eq!(MessagesView::scroll_rows(10, 3, 4), (36, 12));
+        assert_eq!(MessagesView::scroll_rows(10, 3, 2), (18, 6));
+        assert_eq!(MessagesView::scroll_rows(10, 9, 3), (27, 27));
+        assert_eq!(MessagesView::scroll_rows(0, 0, 3), (0, 0));
+    }
+
+    #[test]
+    fn item_height_follows_content_newlines() {
+        assert_eq!(MessagesView::item_height(ContentNewlines::Strip), 4);
+        assert_eq!(MessagesView::item_height(ContentNewlines::Symbol), 4);
+        assert_eq!(MessagesView::item_height(ContentNewlines::Keep), 6);
+    }
+
+    #[test]
+    fn item_at_row_spans_wrapped_rows() {
+        // Each item takes three rows, so rows 0..3 are the first item and 3..6 the second.
+        assert_eq!(MessagesView::item_at_row(0, 0, 12, 3), Some(0));
+        assert_eq!(MessagesView::item_at_row(0, 2, 12, 3), Some(0));
+        assert_eq!(MessagesView::item_at_row(0, 3, 12, 3), Some(1));
+        assert_eq!(MessagesView::item_at_row(5, 7, 12, 3), Some(7));
+    }
+
+    #[test]
+    fn item_at_row_ignores_partially_visible_last_item() {
+        // 10 rows fit two whole 4 row items, leaving rows 8 and 9 empty.
+        assert_eq!(MessagesView::item_at_row(0, 7, 10, 4), Some(1));
+        assert_eq!(MessagesView::item_at_row(0, 8, 10, 4), None);
+        assert_eq!(MessagesView::item_at_row(0, 9, 10, 4), None);
+    }
+
+    #[test]
+    fn item_at_row_follows_resize() {
+        assert_eq!(MessagesView::item_at_row(2, 9, 12, 4), Some(4));
+        // Shrinking the table leaves only one whole item on screen.
+        assert_eq!(MessagesView::item_at_row(2, 9, 7, 4), None);
+        assert_eq!(MessagesView::item_at_row(2, 3, 7, 4), Some(2));
+        // Too short to fit any item at all.
+        assert_eq!(MessagesView::item_at_row(2, 0, 3, 4), None);
+    }
 }
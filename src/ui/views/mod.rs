@@ -5,21 +5,36 @@ mod main_menu;
 mod messages;
 mod phonebook;
 
+pub use messages::MessagesPage;
+
 use crate::app::AppContext;
 use crate::error::{AppError, AppResult};
-use crate::modals::{AppModal, ModalResponse};
+use crate::glyphs::glyphs;
+use crate::modals::{AppModal, ModalMetadata, ModalResponse};
+use crate::sanitize::sanitize_text;
 use crate::theme::Theme;
-use crate::types::AppAction;
-use crate::ui::{ModalResponderComponent, ViewBase};
-use crossterm::event::KeyEvent;
+use crate::types::{AppAction, ContentNewlines};
+use crate::ui::{KeyHint, ModalResponderComponent, ViewBase};
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::layout::Rect;
 use ratatui::Frame;
+use sms_client::http::HttpClient;
 use sms_client::types::sms::SmsMessage;
+use std::collections::VecDeque;
 use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
 
 /*
    Quite happy with this, instead of storing every initialized view on the
    App itself the ViewManager now creates views as they are needed based on
    ViewStateRequests (used in AppAction::SetViewState).
+
+   The exception is conversations, which are slow to load. The last few left
+   are kept (if `view_cache_size` allows) and shown again instead of created.
+   A cached view skips `load()`, and is `resume()`d instead, which merges in
+   the newest page since live messages only reach the current view. Compose
+   isn't cached, as leaving it discards the draft on purpose.
 */
 
 /// Public request interface for switching views.
@@ -30,22 +45,49 @@ pub enum ViewStateRequest {
     DeviceInfo,
     Messages {
         phone_number: String,
-        reversed: bool,
+        reversed: Option<bool>, // None uses the remembered order for the number
+    },
+    SearchMessages {
+        phone_number: String,
+        query: String,
     },
     Compose {
         phone_number: String,
+        initial_text: Option<String>,
     },
     Error {
         message: String,
         dismissible: bool,
+        retry: Option<Box<ViewStateRequest>>, // Request that failed, to re-attempt
     },
 }
 impl ViewStateRequest {
-    /// Create `ViewState::ViewMessages` with a default reversed state.
+    /// Create `ViewState::ViewMessages` with the remembered reversed state.
     pub fn view_messages(phone_number: &str) -> Self {
         Self::Messages {
             phone_number: phone_number.to_string(),
-            reversed: false,
+            reversed: None,
+        }
+    }
+
+    /// Create `ViewState::Compose` with an empty text buffer.
+    pub fn compose(phone_number: &str) -> Self {
+        Self::Compose {
+            phone_number: phone_number.to_string(),
+            initial_text: None,
+        }
+    }
+
+    /// The kind of view requested, for logging without phone numbers or message content.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MainMenu => "main menu",
+            Self::Phonebook => "phonebook",
+            Self::DeviceInfo => "device info",
+            Self::Messages { .. } => "messages",
+            Self::SearchMessages { .. } => "message search",
+            Self::Compose { .. } => "compose",
+            Self::Error { .. } => "error",
         }
     }
 }
@@ -59,44 +101,214 @@ impl From<AppError> for ViewStateRequest {
         Self::Error {
             message: error.to_string(),
             dismissible: false,
+            retry: None,
         }
     }
 }
 
+/// How a `ViewManager` transition ended.
+pub enum TransitionOutcome {
+    Loaded,
+    Failed(String),                         // the error shown
+    Unauthorized(ViewStateRequest, String), // request that was rejected, to retry
+    Cancelled,
+}
+
+/// Conversations whose position is remembered, most recently left last. Each is only a
+/// message ID and count, as holding whole views (up to `max_loaded_messages` records each)
+/// would cost far more memory for a few saved requests.
+const MAX_REMEMBERED_POSITIONS: usize = 5;
+
 /// Track the current view, and create
 pub struct ViewManager {
     current: CurrentView,
     context: AppContext,
+    positions: VecDeque<(String, messages::MessagesPosition)>, // LRU by phone number
+    cache: VecDeque<(String, CurrentView)>, // LRU by cache key, most recently left last
 }
 impl ViewManager {
     pub fn new(context: AppContext) -> Self {
         let current = CurrentView::from_request(ViewStateRequest::default(), &context);
-        Self { current, context }
+        Self {
+            current,
+            context,
+            positions: VecDeque::new(),
+            cache: VecDeque::new(),
+        }
     }
 
-    pub async fn transition_to(&mut self, request: ViewStateRequest) {
-        let mut new_view = CurrentView::from_request(request.clone(), &self.context);
+    /// Keep a view that is being left, if it can be cached, evicting the least recent.
+    fn cache_view(&mut self, view: CurrentView) {
+        let capacity = self.context.options.view_cache_size;
+        let Some(key) = view.cache_key() else {
+            return;
+        };
+        if capacity == 0 {
+            return;
+        }
 
-        // Attempt to load, showing an ErrorView if it fails.
-        if let Err(e) = new_view.load().await {
-            new_view = CurrentView::from_request(
-                ViewStateRequest::Error {
-                    message: e.to_string(),
-                    dismissible: false,
-                },
-                &self.context,
-            );
+        self.cache.retain(|(cached, _)| *cached != key);
+        if self.cache.len() >= capacity {
+            self.cache.pop_front();
         }
+        self.cache.push_back((key, view));
+    }
 
-        self.current = new_view;
+    /// Take the cached view for a request, if any.
+    fn take_cached(&mut self, request: &ViewStateRequest) -> Option<CurrentView> {
+        let key = CurrentView::request_cache_key(request, &self.context)?;
+        let idx = self.cache.iter().position(|(cached, _)| *cached == key)?;
+        self.cache.remove(idx).map(|(_, view)| view)
+    }
+
+    /// Drop cached views, eg: for one conversation after reloading it, or all of them.
+    pub fn invalidate_cache(&mut self, phone_number: Option<&str>) {
+        match phone_number {
+            Some(phone_number) => self.cache.retain(|(_, view)| {
+                !matches!(view, CurrentView::Messages { phone_number: cached, .. } if cached == phone_number)
+            }),
+            None => self.cache.clear(),
+        }
+    }
+
+    /// Remember where the current conversation was left, evicting the least recent.
+    fn remember_position(&mut self) {
+        let CurrentView::Messages {
+            view, phone_number, ..
+        } = &self.current
+        else {
+            return;
+        };
+        let Some(position) = view.position() else {
+            return;
+        };
+
+        self.positions.retain(|(number, _)| number != phone_number);
+        if self.positions.len() >= MAX_REMEMBERED_POSITIONS {
+            self.positions.pop_front();
+        }
+        self.positions.push_back((phone_number.clone(), position));
+    }
+
+    /// Take the remembered position for a conversation, if any.
+    fn take_position(&mut self, phone_number: &str) -> Option<messages::MessagesPosition> {
+        let idx = self
+            .positions
+            .iter()
+            .position(|(number, _)| number == phone_number)?;
+        self.positions.remove(idx).map(|(_, position)| position)
+    }
+
+    /// A request that would recreate the current view, eg: to reload or restore it.
+    pub fn current_request(&self) -> ViewStateRequest {
+        self.current.to_request()
+    }
+
+    /// Load and switch to the requested view. If the cancel future completes first
+    /// the load is dropped (so no late response can apply) and the current view is kept.
+    pub async fn transition_to(
+        &mut self,
+        request: ViewStateRequest,
+        cancel: impl Future<Output = ()>,
+    ) -> TransitionOutcome {
+        let cached = self.take_cached(&request);
+        let resumed = cached.is_some();
+        let mut new_view =
+            cached.unwrap_or_else(|| CurrentView::from_request(request.clone(), &self.context));
+        self.remember_position();
+        if let CurrentView::Messages {
+            view, phone_number, ..
+        } = &mut new_view
+        {
+            if !resumed && view.search().is_none() {
+                view.set_restore_position(self.take_position(phone_number));
+            }
+        }
+        let retry = Some(Box::new(request.clone()));
+        let timeout = self.context.options.load_timeout;
+        let mut unauthorized = false;
+
+        // Attempt to load, showing an ErrorView if it fails or takes too long.
+        let error = tokio::select! {
+            result = tokio::time::timeout(timeout, new_view.load_or_resume(resumed)) => match result {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => {
+                    tracing::warn!("Failed to load {} view: {e}", request.kind());
+                    unauthorized = e.is_unauthorized();
+                    Some((e.to_string(), false))
+                }
+                Err(_) => {
+                    tracing::warn!(
+                        "Loading {} view timed out after {}s",
+                        request.kind(),
+                        timeout.as_secs()
+                    );
+                    Some((
+                        format!(
+                            "Loading timed out after {}s, the server may be slow or unreachable.\nDismiss and try again.",
+                            timeout.as_secs()
+                        ),
+                        true,
+                    ))
+                }
+            },
+            () = cancel => {
+                tracing::debug!("Loading {} view was cancelled", request.kind());
+                if resumed {
+                    self.cache_view(new_view);
+                }
+                return TransitionOutcome::Cancelled;
+            }
+        };
+
+        let outcome = match error {
+            Some((message, dismissible)) => {
+                new_view = CurrentView::from_request(
+                    ViewStateRequest::Error {
+                        message: message.clone(),
+                        dismissible,
+                        retry,
+                    },
+                    &self.context,
+                );
+                if unauthorized {
+                    TransitionOutcome::Unauthorized(request, message)
+                } else {
+                    TransitionOutcome::Failed(message)
+                }
+            }
+            None => TransitionOutcome::Loaded,
+        };
+
+        // Reloading a conversation replaces it, so the old one isn't worth keeping.
+        let previous = std::mem::replace(&mut self.current, new_view);
+        if previous.cache_key() != self.current.cache_key() {
+            self.cache_view(previous);
+        }
+        outcome
+    }
+
+    /// Re-point the context at a new client, used by any views created from now on.
+    pub fn set_http_client(&mut self, http: Arc<HttpClient>) {
+        self.context.http = http;
+        // Cached views hold the old client in their context.
+        self.invalidate_cache(None);
     }
 
     pub async fn handle_key(&mut self, key: KeyEvent) -> Option<AppAction> {
         self.current.handle_key(key).await
     }
 
-    pub fn render(&mut self, frame: &mut Frame, theme: &Theme) {
-        self.current.render(frame, theme);
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        self.current.render(frame, area, theme);
+    }
+
+    pub fn key_hints(&self) -> Vec<KeyHint> {
+        self.current.key_hints()
+    }
+
+    pub async fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<AppAction> {
+        self.current.handle_mouse(mouse).await
     }
 
     pub fn handle_modal_response(
@@ -108,10 +320,95 @@ impl ViewManager {
         self.current.handle_modal_response(modal, response)
     }
 
+    pub fn handle_modal_mouse(
+        &mut self,
+        modal: &mut AppModal,
+        mouse: MouseEvent,
+    ) -> Option<AppAction> {
+        let response = modal.handle_mouse(mouse)?;
+        self.current.handle_modal_response(modal, response)
+    }
+
     pub fn try_add_message(&mut self, message: &SmsMessage) -> bool {
         self.current.try_add_message(message)
     }
 
+    /// The phone number of the open conversation, if any.
+    pub fn messages_phone_number(&self) -> Option<&str> {
+        match &self.current {
+            CurrentView::Messages { phone_number, .. } => Some(phone_number),
+            _ => None,
+        }
+    }
+
+    /// Merge refreshed messages into the open conversation, if it's still for that number.
+    pub async fn merge_messages(
+        &mut self,
+        for_number: &str,
+        messages: Vec<SmsMessage>,
+        limit: u64,
+    ) -> AppResult<()> {
+        if let CurrentView::Messages {
+            view, phone_number, ..
+        } = &mut self.current
+        {
+            if phone_number == for_number {
+                view.merge_newest_page(phone_number, messages, limit)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Hand a page loaded in the background to the open conversation, if it's still for
+    /// that number. Pages for a conversation that has since been left are dropped.
+    pub fn apply_messages_page(&mut self, page: MessagesPage) -> Option<AppAction> {
+        match &mut self.current {
+            CurrentView::Messages {
+                view, phone_number, ..
+            } if *phone_number == page.phone_number => view.apply_page(page),
+            _ => None,
+        }
+    }
+
+    /// Show a message being sent in the open conversation, if it's for that number.
+    pub fn add_pending_message(
+        &mut self,
+        for_number: &str,
+        pending_id: i64,
+        content: &str,
+        parts: usize,
+    ) {
+        if let CurrentView::Messages {
+            view, phone_number, ..
+        } = &mut self.current
+        {
+            if phone_number == for_number {
+                view.add_pending(pending_id, phone_number, content, parts);
+            }
+        }
+    }
+
+    /// Settle a message being sent, in the open conversation and any cached ones.
+    pub fn resolve_pending_message(
+        &mut self,
+        for_number: &str,
+        pending_id: i64,
+        message_id: Option<i64>,
+    ) {
+        let cached = self.cache.iter_mut().map(|(_, view)| view);
+        for view in std::iter::once(&mut self.current).chain(cached) {
+            if let CurrentView::Messages {
+                view, phone_number, ..
+            } = view
+            {
+                if phone_number == for_number {
+                    view.resolve_pending(pending_id, message_id);
+                }
+            }
+        }
+    }
+
     pub fn should_show_error(&self, new_dismissible: bool) -> bool {
         match self.current.is_dismissible_error() {
             Some(existing_dismissible) => existing_dismissible || !new_dismissible,
@@ -145,12 +442,15 @@ enum CurrentView {
         view: error::ErrorView,
         message: String,
         dismissible: bool,
+        retry: Option<Box<ViewStateRequest>>,
     },
 }
 impl CurrentView {
     fn from_request(request: ViewStateRequest, context: &AppContext) -> Self {
         match request {
-            ViewStateRequest::MainMenu => CurrentView::MainMenu(main_menu::MainMenuView::new()),
+            ViewStateRequest::MainMenu => CurrentView::MainMenu(
+                main_menu::MainMenuView::new().with_vim_keys(context.options.vim_keys),
+            ),
             ViewStateRequest::Phonebook => {
                 CurrentView::Phonebook(phonebook::PhonebookView::with_context(context.clone()))
             }
@@ -162,24 +462,113 @@ impl CurrentView {
                 reversed,
             } => CurrentView::Messages {
                 view: messages::MessagesView::with_context(context.clone()),
+                reversed: reversed.unwrap_or_else(|| context.state.is_reversed(&phone_number)),
                 phone_number,
-                reversed,
             },
-            ViewStateRequest::Compose { phone_number } => CurrentView::Compose {
-                view: compose::ComposeView::with_context(context.clone()),
+            ViewStateRequest::SearchMessages {
+                phone_number,
+                query,
+            } => CurrentView::Messages {
+                view: messages::MessagesView::with_context(context.clone())
+                    .with_search(Some(query)),
+                reversed: context.state.is_reversed(&phone_number),
+                phone_number,
+            },
+            ViewStateRequest::Compose {
+                phone_number,
+                initial_text,
+            } => CurrentView::Compose {
+                view: compose::ComposeView::with_context(context.clone())
+                    .with_initial_text(initial_text),
                 phone_number,
             },
             ViewStateRequest::Error {
                 message,
                 dismissible,
+                retry,
             } => CurrentView::Error {
                 view: error::ErrorView::new(),
                 message,
                 dismissible,
+                retry,
             },
         }
     }
 
+    /// Identifies a view that can be cached: a conversation (not a search) in one order.
+    fn cache_key(&self) -> Option<String> {
+        match self {
+            CurrentView::Messages {
+                view,
+                phone_number,
+                reversed,
+            } if view.search().is_none() => Some(format!("{phone_number}:{reversed}")),
+            _ => None,
+        }
+    }
+
+    /// The cache key of the view a request would create, resolving the remembered order.
+    fn request_cache_key(request: &ViewStateRequest, context: &AppContext) -> Option<String> {
+        match request {
+            ViewStateRequest::Messages {
+                phone_number,
+                reversed,
+            } => {
+                let reversed = reversed.unwrap_or_else(|| context.state.is_reversed(phone_number));
+                Some(format!("{phone_number}:{reversed}"))
+            }
+            _ => None,
+        }
+    }
+
+    /// Reconstruct the request for this view from the state it holds.
+    /// Messages keep their resolved order, and compose keeps any unsent draft.
+    fn to_request(&self) -> ViewStateRequest {
+        match self {
+            CurrentView::MainMenu(_) => ViewStateRequest::MainMenu,
+            CurrentView::Phonebook(_) => ViewStateRequest::Phonebook,
+            CurrentView::DeviceInfo(_) => ViewStateRequest::DeviceInfo,
+            CurrentView::Messages {
+                view,
+                phone_number,
+                reversed,
+            } => match view.search() {
+                Some(query) => ViewStateRequest::SearchMessages {
+                    phone_number: phone_number.clone(),
+                    query: query.to_string(),
+                },
+                None => ViewStateRequest::Messages {
+                    phone_number: phone_number.clone(),
+                    reversed: Some(*reversed),
+                },
+            },
+            CurrentView::Compose { view, phone_number } => ViewStateRequest::Compose {
+                phone_number: phone_number.clone(),
+                initial_text: view.draft(),
+            },
+            CurrentView::Error {
+                message,
+                dismissible,
+                retry,
+                ..
+            } => ViewStateRequest::Error {
+                message: message.clone(),
+                dismissible: *dismissible,
+                retry: retry.clone(),
+            },
+        }
+    }
+
+    /// Load a newly created view, or resume a cached one.
+    async fn load_or_resume(&mut self, resumed: bool) -> AppResult<()> {
+        match self {
+            CurrentView::Messages {
+                view, phone_number, ..
+            } if resumed => view.resume(phone_number).await,
+            _ => self.load().await,
+        }
+    }
+
     async fn load(&mut self) -> AppResult<()> {
         match self {
             CurrentView::MainMenu(view) => view.load(()).await,
@@ -211,27 +600,69 @@ impl CurrentView {
                 view,
                 message,
                 dismissible,
-            } => view.handle_key(key, (message, *dismissible)).await,
+                retry,
+            } => {
+                view.handle_key(key, (message, *dismissible, retry.as_deref()))
+                    .await
+            }
+        }
+    }
+
+    /// Only the messages table currently supports the mouse.
+    async fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<AppAction> {
+        match self {
+            CurrentView::Messages {
+                view, phone_number, ..
+            } => view.handle_mouse(mouse, phone_number).await,
+            _ => None,
         }
     }
 
     #[inline]
-    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        match self {
+            CurrentView::MainMenu(view) => view.render(frame, area, theme, ()),
+            CurrentView::Phonebook(view) => view.render(frame, area, theme, ()),
+            CurrentView::DeviceInfo(view) => view.render(frame, area, theme, ()),
+            CurrentView::Messages {
+                view,
+                phone_number,
+                reversed,
+            } => view.render(frame, area, theme, (phone_number, *reversed)),
+            CurrentView::Compose { view, phone_number } => {
+                view.render(frame, area, theme, phone_number)
+            }
+            CurrentView::Error {
+                view,
+                message,
+                dismissible,
+                retry,
+            } => view.render(
+                frame,
+                area,
+                theme,
+                (message, *dismissible, retry.as_deref()),
+            ),
+        }
+    }
+
+    fn key_hints(&self) -> Vec<KeyHint> {
         match self {
-            CurrentView::MainMenu(view) => view.render(frame, theme, ()),
-            CurrentView::Phonebook(view) => view.render(frame, theme, ()),
-            CurrentView::DeviceInfo(view) => view.render(frame, theme, ()),
+            CurrentView::MainMenu(view) => view.key_hints(()),
+            CurrentView::Phonebook(view) => view.key_hints(()),
+            CurrentView::DeviceInfo(view) => view.key_hints(()),
             CurrentView::Messages {
                 view,
                 phone_number,
                 reversed,
-            } => view.render(frame, theme, (phone_number, *reversed)),
-            CurrentView::Compose { view, phone_number } => view.render(frame, theme, phone_number),
+            } => view.key_hints((phone_number, *reversed)),
+            CurrentView::Compose { view, phone_number } => view.key_hints(phone_number),
             CurrentView::Error {
                 view,
                 message,
                 dismissible,
-            } => view.render(frame, theme, (message, *dismissible)),
+                retry,
+            } => view.key_hints((message, *dismissible, retry.as_deref())),
         }
     }
 
@@ -245,10 +676,52 @@ impl CurrentView {
         if matches!(response, ModalResponse::Dismissed) {
             return Some(AppAction::SetModal(None));
         }
+        if matches!(response, ModalResponse::Reload) {
+            return Some(AppAction::ReloadModal);
+        }
+
+        // Re-authentication is handled by the app, since it owns the client.
+        if modal.id == "reauthenticate" {
+            return match (response, &modal.metadata) {
+                (ModalResponse::TextInput(Some(token)), ModalMetadata::Reauthenticate(retry)) => {
+                    Some(AppAction::Reauthenticate {
+                        token,
+                        retry: retry.clone(),
+                    })
+                }
+                _ => None,
+            };
+        }
+
+        // Go to number is available from any view, so isn't handled by them.
+        if modal.id == "go_to_number" {
+            return match response {
+                ModalResponse::TextInput(Some(phone_number)) => Some(AppAction::SetViewState {
+                    state: ViewStateRequest::view_messages(&phone_number),
+                    dismiss_modal: true,
+                }),
+                _ => None,
+            };
+        }
+
+        // Naming a number is started from a notification, so also isn't handled by views.
+        if modal.id == "name_number" {
+            return match (response, &modal.metadata) {
+                (
+                    ModalResponse::TextInput(Some(name)),
+                    ModalMetadata::PhoneNumber(phone_number),
+                ) => Some(AppAction::SetFriendlyName {
+                    phone_number: phone_number.clone(),
+                    friendly_name: name,
+                }),
+                _ => None,
+            };
+        }
 
         match self {
             CurrentView::Phonebook(view) => view.handle_modal_response(modal, response),
             CurrentView::Compose { view, .. } => view.handle_modal_response(modal, response),
+            CurrentView::Messages { view, .. } => view.handle_modal_response(modal, response),
             _ => match response {
                 // If the modal is being dismissed, it doesn't matter if it doesn't have a handler.
                 ModalResponse::Dismissed => None,
@@ -261,19 +734,24 @@ impl CurrentView {
     }
 
     fn try_add_message(&mut self, message: &SmsMessage) -> bool {
-        if let CurrentView::Messages {
-            view, phone_number, ..
-        } = self
-        {
-            if phone_number == &message.phone_number {
+        match self {
+            CurrentView::Messages {
+                view, phone_number, ..
+            } if phone_number == &message.phone_number => {
                 // Suppress the notification from showing, since we're already
                 // on the view that the notification would take us to anyway.
                 view.add_live_message(message.clone());
-                return true;
+                true
             }
+            CurrentView::Phonebook(view) => {
+                // Bubble the number to the top of recent contacts (already touched
+                // in the cache), but still show the notification since the
+                // message itself isn't visible.
+                view.sync_contacts();
+                false
+            }
+            _ => false,
         }
-
-        false
     }
 
     fn is_dismissible_error(&self) -> Option<bool> {
@@ -289,11 +767,21 @@ impl Display for CurrentView {
             Self::MainMenu { .. } => write!(f, "Main Menu"),
             Self::Phonebook { .. } => write!(f, "Phonebook"),
             Self::DeviceInfo { .. } => write!(f, "Device Info"),
-            Self::Messages { phone_number, .. } => {
-                write!(f, "Viewing Messages ｜ {phone_number}")
+            // Used as the terminal title, so the number mustn't be able to end the sequence.
+            Self::Messages {
+                view, phone_number, ..
+            } => {
+                let name = view.display_name(phone_number);
+                let separator = glyphs().separator;
+                if view.search().is_some() {
+                    write!(f, "Searching Messages {separator} {name}")
+                } else {
+                    write!(f, "Viewing Messages {separator} {name}")
+                }
             }
             Self::Compose { phone_number, .. } => {
-                write!(f, "Composing Message ｜ {phone_number}")
+                let phone_number = sanitize_text(phone_number, ContentNewlines::Strip);
+                write!(f, "Composing Message {} {phone_number}", glyphs().separator)
             }
             Self::Error { dismissible, .. } => {
                 write!(f, "{}", if *dismissible { "Fatal Error" } else { "Error" })
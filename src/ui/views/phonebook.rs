@@ -1,27 +1,32 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Clear, List, ListItem, Paragraph};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
 
 use crate::app::AppContext;
+use crate::contacts::Contact;
 use crate::error::AppResult;
+use crate::glyphs::glyphs;
 use crate::modals::{AppModal, ModalMetadata, ModalResponse};
+use crate::sanitize::sanitize_text;
 use crate::theme::Theme;
-use crate::types::AppAction;
+use crate::types::{AppAction, ContentNewlines};
+use crate::ui::modals::confirmation::ConfirmationModal;
 use crate::ui::modals::text_input::TextInputModal;
 use crate::ui::notifications::NotificationType;
 use crate::ui::views::ViewStateRequest;
-use crate::ui::{centered_rect, ModalResponderComponent, ViewBase};
-use sms_client::error::ClientError;
-use sms_client::types::http::{HttpPaginationOptions, LatestNumberFriendlyNamePair};
+use crate::ui::{centered_rect, vim_navigation_key, KeyHint, ModalResponderComponent, ViewBase};
 
 pub struct PhonebookView {
     context: AppContext,
-    recent_contacts: Vec<LatestNumberFriendlyNamePair>, // (phone, friendly name)
+    recent_contacts: Vec<Contact>,
     selected_contact: Option<usize>,
     input_buffer: String,
-    max_contacts: usize,
+    scroll_offset: usize, // First contact shown in the list
+    show_archived: bool,
 }
 impl PhonebookView {
     pub fn with_context(context: AppContext) -> Self {
@@ -31,7 +36,8 @@ impl PhonebookView {
             recent_contacts,
             selected_contact: None,
             input_buffer: String::new(),
-            max_contacts: 14,
+            scroll_offset: 0,
+            show_archived: false,
         }
     }
 
@@ -63,10 +69,104 @@ impl PhonebookView {
         self.selected_contact = None;
     }
 
+    /// Refresh the recent contacts from the app level cache,
+    /// keeping the current selection pointed at the same contact.
+    pub fn sync_contacts(&mut self) {
+        let selected_number = self
+            .selected_contact
+            .and_then(|i| self.recent_contacts.get(i))
+            .map(|contact| contact.number.clone());
+
+        self.recent_contacts = self.context.contacts.contacts().unwrap_or_default();
+        if !self.show_archived {
+            self.recent_contacts
+                .retain(|contact| !self.context.state.is_archived(&contact.number));
+        }
+        if let Some(max_contacts) = self.context.options.phonebook_max_contacts {
+            self.recent_contacts.truncate(max_contacts);
+        }
+
+        // Follow the previously selected contact, or clear if it was pushed out.
+        self.selected_contact = selected_number.and_then(|number| {
+            self.recent_contacts
+                .iter()
+                .position(|contact| contact.number == number)
+        });
+    }
+
+    /// Archive the selected contact, or unarchive it if it already is.
+    fn toggle_selected_archived(&mut self) -> Option<()> {
+        let number = self
+            .recent_contacts
+            .get(self.selected_contact?)?
+            .number
+            .clone();
+        let archived = !self.context.state.is_archived(&number);
+
+        self.context.state.set_archived(&number, archived);
+        self.sync_contacts();
+        Some(())
+    }
+
+    /// Set (or remove, with None) a friendly name on the server, updating the shared
+    /// cache straight away rather than waiting for the request.
+    fn set_friendly_name(
+        &mut self,
+        phone_number: String,
+        friendly_name: Option<String>,
+    ) -> Option<AppAction> {
+        let http_client = self.context.http.clone();
+        let cloned_name = friendly_name.clone();
+        let cloned_phone = phone_number.clone();
+        let sender = self.context.sender.clone();
+
+        tokio::spawn(async move {
+            let action = if cloned_name.is_some() {
+                "change"
+            } else {
+                "remove"
+            };
+            if http_client
+                .set_friendly_name(&cloned_phone, cloned_name)
+                .await
+                .is_err()
+            {
+                // If the edit failed, show a notification.
+                // It's not worth changing to the error state just over a failed friendly name change.
+                let notification = NotificationType::Failure {
+                    title: "Edit Failed".to_string(),
+                    message: format!("Failed to {action} friendly name for {cloned_phone}"),
+                };
+                let _ = sender.send(AppAction::ShowNotification(notification));
+            }
+        });
+
+        // Update shared cache
+        self.context
+            .contacts
+            .set_friendly_name(&phone_number, friendly_name);
+        self.sync_contacts();
+
+        Some(AppAction::SetModal(None))
+    }
+
+    /// Move the scroll offset just enough to keep the selected contact in view.
+    fn scroll_to_selection(&mut self, visible: usize) {
+        if let Some(selected) = self.selected_contact {
+            if selected < self.scroll_offset {
+                self.scroll_offset = selected;
+            } else if selected >= self.scroll_offset + visible {
+                self.scroll_offset = selected + 1 - visible;
+            }
+        }
+        let max_offset = self.recent_contacts.len().saturating_sub(visible);
+        self.scroll_offset = self.scroll_offset.min(max_offset);
+    }
+
     fn get_max_phone_length(&self) -> usize {
         self.recent_contacts
             .iter()
-            .map(|pair| pair.number.len())
+            .map(|pair| sanitize_text(&pair.number, ContentNewlines::Strip).width())
             .max()
             .unwrap_or(0)
     }
@@ -75,31 +175,26 @@ impl ViewBase for PhonebookView {
     type Context<'ctx> = ();
 
     async fn load(&mut self, _ctx: Self::Context<'_>) -> AppResult<()> {
-        if !self.recent_contacts.is_empty() {
-            return Ok(());
+        // Only request latest contacts if the shared cache is stale.
+        if self.context.contacts.is_stale() {
+            self.context.contacts.fetch(&self.context.http).await?;
         }
 
-        // Request first page of latest contacts.
-        let pagination = HttpPaginationOptions::default().with_limit(self.max_contacts as u64);
-        self.recent_contacts = self
-            .context
-            .0
-            .get_latest_numbers(Some(pagination))
-            .await
-            .map_err(ClientError::from)?
-            .into_iter()
-            .collect();
-
-        // Reset selection if OOB
-        if let Some(selected) = self.selected_contact {
-            if selected >= self.recent_contacts.len() {
-                self.selected_contact = None;
-            }
-        }
+        self.sync_contacts();
         Ok(())
     }
 
     async fn handle_key(&mut self, key: KeyEvent, _ctx: Self::Context<'_>) -> Option<AppAction> {
+        // Typing a number takes precedence, so j/k only navigate while the input is empty.
+        let key = if self.context.options.vim_keys && self.input_buffer.is_empty() {
+            match vim_navigation_key(key).code {
+                code @ (KeyCode::Up | KeyCode::Down) => KeyEvent { code, ..key },
+                _ => key,
+            }
+        } else {
+            key
+        };
+
         match key.code {
             KeyCode::Esc => {
                 return Some(AppAction::SetViewState {
@@ -111,12 +206,13 @@ impl ViewBase for PhonebookView {
                 let selected = self.selected_contact?;
                 let pair = self.recent_contacts.get(selected)?;
 
+                let number = sanitize_text(&pair.number, ContentNewlines::Strip);
                 let mut ui =
-                    TextInputModal::new("Edit Friendly Name", format!("Name for {}", pair.number))
+                    TextInputModal::new("Edit Friendly Name", format!("Name for {number}"))
                         .with_max_length(50);
 
                 if let Some(existing) = &pair.friendly_name {
-                    ui = ui.with_initial_value(existing);
+                    ui = ui.with_initial_value(sanitize_text(existing, ContentNewlines::Strip));
                 }
 
                 // Include selected phone number in modal metadata for the response!
@@ -125,6 +221,25 @@ impl ViewBase for PhonebookView {
 
                 return Some(AppAction::SetModal(Some(modal)));
             }
+            KeyCode::Char('d' | 'D') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let selected = self.selected_contact?;
+                let pair = self.recent_contacts.get(selected)?;
+                let name = sanitize_text(pair.friendly_name.as_ref()?, ContentNewlines::Strip);
+                let number = sanitize_text(&pair.number, ContentNewlines::Strip);
+
+                let ui = ConfirmationModal::new(format!("Remove the name '{name}' from {number}?"));
+                let modal = AppModal::new("delete_friendly_name", ui)
+                    .with_metadata(ModalMetadata::PhoneNumber(pair.number.clone()));
+
+                return Some(AppAction::SetModal(Some(modal)));
+            }
+            KeyCode::Char('a' | 'A') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_selected_archived();
+            }
+            KeyCode::Char('s' | 'S') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.show_archived = !self.show_archived;
+                self.sync_contacts();
+            }
             KeyCode::Enter => {
                 let current_phone = self
                     .selected_contact
@@ -167,8 +282,8 @@ impl ViewBase for PhonebookView {
         None
     }
 
-    fn render(&mut self, frame: &mut Frame, theme: &Theme, _ctx: Self::Context<'_>) {
-        let area = centered_rect(50, 35, frame.area());
+    fn render(&mut self, frame: &mut Frame, area: Rect, theme: &Theme, _ctx: Self::Context<'_>) {
+        let area = centered_rect(50, 35, area);
         frame.render_widget(Clear, area);
 
         let block = Block::bordered()
@@ -183,15 +298,17 @@ impl ViewBase for PhonebookView {
         let mut constraints = vec![
             Constraint::Length(1), // Prompt
             Constraint::Length(3), // Input box
-            Constraint::Length(1), // Help text
         ];
         if !self.recent_contacts.is_empty() {
             constraints.push(Constraint::Length(1)); // Spacing
             constraints.push(Constraint::Length(1)); // Recent contacts header
 
-            // Get height for contacts box
-            let contacts_height =
-                std::cmp::min(u16::try_from(self.recent_contacts.len()).unwrap_or(0), 8);
+            // Fill the remaining height, scrolling the list if they don't all fit
+            let available = inner.height.saturating_sub(6);
+            let contacts_height = std::cmp::min(
+                u16::try_from(self.recent_contacts.len()).unwrap_or(0),
+                available,
+            );
             constraints.push(Constraint::Length(contacts_height));
         }
         let layout = Layout::vertical(constraints).split(inner);
@@ -227,37 +344,63 @@ impl ViewBase for PhonebookView {
                 );
         frame.render_widget(input, layout[1]);
 
-        // Controls help
-        let help_text = if self.recent_contacts.is_empty() {
-            "(Enter) confirm, (Esc) menu"
-        } else if self.selected_contact.is_some() {
-            "↑↓ select, (Enter) confirm, (Ctrl+E) edit name, (Esc) menu"
-        } else {
-            "↑↓ select contact, (Enter) confirm, (Esc) menu"
-        };
-
-        let help = Paragraph::new(help_text)
-            .style(Style::default().fg(theme.text_muted))
-            .alignment(Alignment::Center);
-        frame.render_widget(help, layout[2]);
-
         // Recent contacts section, if there are some
         if !self.recent_contacts.is_empty() {
-            let header = Paragraph::new("Recent Contacts:").style(theme.secondary_style);
-            frame.render_widget(header, layout[4]);
+            let header = if self.show_archived {
+                "Recent Contacts (including archived):"
+            } else {
+                "Recent Contacts:"
+            };
+            let visible = usize::from(layout[4].height);
+            self.scroll_to_selection(visible);
+            let above = self.scroll_offset;
+            let below = self
+                .recent_contacts
+                .len()
+                .saturating_sub(self.scroll_offset + visible);
+
+            // Show how many contacts are scrolled out of view on either side
+            let mut header = vec![Span::styled(header, theme.secondary_style)];
+            if above > 0 {
+                header.push(Span::styled(
+                    format!("  {} {above} more", glyphs().arrow_up),
+                    Style::default().fg(theme.text_muted),
+                ));
+            }
+            if below > 0 {
+                header.push(Span::styled(
+                    format!("  {} {below} more", glyphs().arrow_down),
+                    Style::default().fg(theme.text_muted),
+                ));
+            }
+            frame.render_widget(Paragraph::new(Line::from(header)), layout[3]);
 
             let max_phone_length = self.get_max_phone_length();
             let items: Vec<ListItem> = self
                 .recent_contacts
                 .iter()
                 .enumerate()
+                .skip(self.scroll_offset)
+                .take(visible)
                 .map(|(i, pair)| {
-                    let content = if let Some(friendly_name) = &pair.friendly_name {
+                    // Sanitized, as names and numbers are set by other clients too.
+                    let number = sanitize_text(&pair.number, ContentNewlines::Strip);
+                    let mut content = if let Some(friendly_name) = &pair.friendly_name {
+                        let friendly_name = sanitize_text(friendly_name, ContentNewlines::Strip);
                         // Pad the phone number to align the separators
-                        format!("{:max_phone_length$} ｜ {friendly_name}", pair.number)
+                        // Padded by display width, as format! pads by character count
+                        let padding = max_phone_length.saturating_sub(number.width());
+                        format!(
+                            "{number}{} {} {friendly_name}",
+                            " ".repeat(padding),
+                            glyphs().separator
+                        )
                     } else {
-                        pair.number.clone()
+                        number
                     };
+                    if self.show_archived && self.context.state.is_archived(&pair.number) {
+                        content.push_str(" (archived)");
+                    }
 
                     let style = if Some(i) == self.selected_contact {
                         Style::default().bg(theme.text_accent).fg(Color::Black)
@@ -269,7 +412,38 @@ impl ViewBase for PhonebookView {
                 .collect();
 
             let list = List::new(items);
-            frame.render_widget(list, layout[5]);
+            frame.render_widget(list, layout[4]);
+        }
+    }
+
+    fn key_hints(&self, _ctx: Self::Context<'_>) -> Vec<KeyHint> {
+        let keys = if self.context.options.vim_keys {
+            format!("{} j/k", glyphs().keys_up_down)
+        } else {
+            glyphs().keys_up_down.to_string()
+        };
+        if self.recent_contacts.is_empty() {
+            vec![
+                ("Enter".into(), "confirm"),
+                ("Ctrl+S".into(), "show archived"),
+                ("Esc".into(), "menu"),
+            ]
+        } else if self.selected_contact.is_some() {
+            vec![
+                (keys, "select"),
+                ("Enter".into(), "confirm"),
+                ("Ctrl+E".into(), "edit name"),
+                ("Ctrl+D".into(), "remove name"),
+                ("Ctrl+A".into(), "archive"),
+                ("Esc".into(), "menu"),
+            ]
+        } else {
+            vec![
+                (keys, "select contact"),
+                ("Enter".into(), "confirm"),
+                ("Ctrl+S".into(), "show archived"),
+                ("Esc".into(), "menu"),
+            ]
         }
     }
 }
@@ -279,47 +453,19 @@ impl ModalResponderComponent for PhonebookView {
         modal: &mut AppModal,
         response: ModalResponse,
     ) -> Option<AppAction> {
-        let phone_number = match &modal.metadata {
-            ModalMetadata::PhoneNumber(phone_number) if modal.id == "edit_friendly_name" => {
-                phone_number
-            }
-            _ => return None,
-        };
-        let friendly_name = match response {
-            ModalResponse::TextInput(friendly_name) => friendly_name?,
-            _ => return None,
+        let ModalMetadata::PhoneNumber(phone_number) = &modal.metadata else {
+            return None;
         };
 
-        let http_client = self.context.0.clone();
-        let cloned_phone = phone_number.clone();
-        let cloned_name = friendly_name.clone();
-        let sender = self.context.1.clone();
-
-        tokio::spawn(async move {
-            if http_client
-                .set_friendly_name(&cloned_phone, Some(cloned_name))
-                .await
-                .is_err()
-            {
-                // If the edit failed, show a notification.
-                // It's not worth changing to the error state just over a failed friendly name change.
-                let notification = NotificationType::Failure {
-                    title: "Edit Failed".to_string(),
-                    message: format!("Failed to change friendly name for {cloned_phone}"),
-                };
-                let _ = sender.send(AppAction::ShowNotification(notification));
+        // Route by modal ID, since several phonebook modals carry the same metadata.
+        match (modal.id.as_str(), response) {
+            ("edit_friendly_name", ModalResponse::TextInput(friendly_name)) => {
+                self.set_friendly_name(phone_number.clone(), Some(friendly_name?))
             }
-        });
-
-        // Update local cache
-        if let Some(contact) = self
-            .recent_contacts
-            .iter_mut()
-            .find(|pair| &pair.number == phone_number)
-        {
-            contact.friendly_name = Some(friendly_name.to_string());
+            ("delete_friendly_name", ModalResponse::Confirmed) => {
+                self.set_friendly_name(phone_number.clone(), None)
+            }
+            _ => None,
         }
-
-        Some(AppAction::SetModal(None))
     }
 }